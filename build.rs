@@ -93,8 +93,12 @@ use crate::Aarch64Architecture::*;
 #[allow(unused_imports)]
 use crate::ArmArchitecture::*;
 #[allow(unused_imports)]
+use crate::BpfVersion::*;
+#[allow(unused_imports)]
 use crate::CustomVendor;
 #[allow(unused_imports)]
+use crate::HexagonArchitecture::*;
+#[allow(unused_imports)]
 use crate::Mips32Architecture::*;
 #[allow(unused_imports)]
 use crate::Mips64Architecture::*;
@@ -104,6 +108,8 @@ use crate::Riscv32Architecture::*;
 use crate::Riscv64Architecture::*;
 #[allow(unused_imports)]
 use crate::X86_32Architecture::*;
+#[allow(unused_imports)]
+use crate::XtensaArchitecture::*;
 
 /// The `Triple` of the current host.
 pub const HOST: Triple = Triple {{
@@ -0,0 +1,39 @@
+#!/bin/bash
+set -euo pipefail
+
+# Regenerate llvm-arch-names.txt with the names that LLVM's
+# `Triple::getArchTypeName` returns for each architecture, using the LLVM
+# that `llvm-config` (or `$LLVM_CONFIG`) points at. The LLVM version is
+# recorded on the first line of the output.
+
+llvm_config="${LLVM_CONFIG:-llvm-config}"
+out="$(dirname "$0")/llvm-arch-names.txt"
+tmp="$(mktemp -d)"
+trap 'rm -rf "$tmp"' EXIT
+
+cat > "$tmp/arch-names.cpp" <<'EOF'
+#if __has_include(<llvm/TargetParser/Triple.h>)
+#include <llvm/TargetParser/Triple.h>
+#else
+#include <llvm/ADT/Triple.h>
+#endif
+#include <cstdio>
+
+int main() {
+  for (int arch = llvm::Triple::UnknownArch + 1; arch <= llvm::Triple::LastArchType;
+       ++arch) {
+    llvm::StringRef name =
+        llvm::Triple::getArchTypeName(static_cast<llvm::Triple::ArchType>(arch));
+    std::printf("%.*s\n", static_cast<int>(name.size()), name.data());
+  }
+}
+EOF
+
+# shellcheck disable=SC2046
+c++ $("$llvm_config" --cxxflags) "$tmp/arch-names.cpp" -o "$tmp/arch-names" \
+    $("$llvm_config" --ldflags --libs support) -Wl,-rpath,"$("$llvm_config" --libdir)"
+
+{
+  echo "# LLVM $("$llvm_config" --version)"
+  "$tmp/arch-names" | sort | uniq
+} > "$out"
@@ -24,20 +24,28 @@ extern crate std as alloc;
 
 mod data_model;
 mod host;
+mod macho;
 mod parse_error;
 mod targets;
 #[macro_use]
 mod triple;
+mod universal;
 
 pub use self::data_model::{CDataModel, Size};
 pub use self::host::HOST;
 pub use self::parse_error::ParseError;
 pub use self::targets::{
-    Aarch64Architecture, Architecture, ArmArchitecture, BinaryFormat, CleverArchitecture,
-    CustomVendor, DeploymentTarget, Environment, Mips32Architecture, Mips64Architecture,
-    OperatingSystem, Riscv32Architecture, Riscv64Architecture, Vendor, X86_32Architecture,
+    Aarch64Architecture, Architecture, ArmArchitecture, ArmFpu, BinaryFormat, BpfVersion,
+    CleverArchitecture, CustomVendor, DeploymentTarget, Environment, HexagonArchitecture,
+    LibcVersion, Mips32Architecture, Mips64Architecture, OperatingSystem, OsRelease,
+    Riscv32Architecture, Riscv64Architecture, RiscvAbi, Vendor, X86_32Architecture,
+    XtensaArchitecture,
 };
-pub use self::triple::{CallingConvention, Endianness, PointerWidth, Triple};
+pub use self::triple::{
+    ApplePlatform, CallingConvention, Endianness, PointerWidth, Triple, WasiPreview,
+    WasmEnvironmentKind, WindowsCrt,
+};
+pub use self::universal::UniversalTarget;
 
 /// A simple wrapper around `Triple` that provides an implementation of
 /// `Default` which defaults to `Triple::host()`.
@@ -0,0 +1,406 @@
+// This file implements detecting a `Triple` from the headers of a Mach-O
+// file or a fat (universal) archive of them.
+
+use crate::targets::{
+    Architecture, BinaryFormat, DeploymentTarget, Environment, OperatingSystem, Vendor,
+};
+use crate::triple::{ApplePlatform, Triple};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+
+// Magic numbers and load commands, from `<mach-o/loader.h>` and
+// `<mach-o/fat.h>`.
+const MH_MAGIC: u32 = 0xfeed_face;
+const MH_CIGAM: u32 = 0xcefa_edfe;
+const MH_MAGIC_64: u32 = 0xfeed_facf;
+const MH_CIGAM_64: u32 = 0xcffa_edfe;
+const FAT_MAGIC: u32 = 0xcafe_babe;
+const FAT_MAGIC_64: u32 = 0xcafe_babf;
+const LC_VERSION_MIN_MACOSX: u32 = 0x24;
+const LC_VERSION_MIN_IPHONEOS: u32 = 0x25;
+const LC_VERSION_MIN_TVOS: u32 = 0x2f;
+const LC_VERSION_MIN_WATCHOS: u32 = 0x30;
+const LC_BUILD_VERSION: u32 = 0x32;
+
+impl Triple {
+    /// Return the triple of each architecture slice in a Mach-O file or a
+    /// fat archive, given the bytes of the file.
+    ///
+    /// The architecture comes from the Mach-O header, and the operating
+    /// system, its deployment target and the environment come from the
+    /// `LC_BUILD_VERSION` or `LC_VERSION_MIN_*` load command. Files without
+    /// either command are reported as `darwin`.
+    ///
+    /// Returns `Err(())` if the bytes aren't a well-formed Mach-O file or fat
+    /// archive, or if they use a CPU or platform that isn't known.
+    pub fn from_macho_bytes(bytes: &[u8]) -> Result<Vec<Self>, ()> {
+        let magic = read_u32(bytes, 0, true)?;
+        let (fat_arch_size, is_64) = match magic {
+            FAT_MAGIC => (20, false),
+            FAT_MAGIC_64 => (32, true),
+            _ => return Ok(vec![triple_from_thin_macho(bytes)?]),
+        };
+
+        // Fat headers are always big-endian.
+        let nfat_arch = read_u32(bytes, 4, true)?;
+        let mut triples = Vec::new();
+        for index in 0..nfat_arch {
+            let fat_arch = usize::try_from(index)
+                .ok()
+                .and_then(|index| index.checked_mul(fat_arch_size))
+                .and_then(|offset| offset.checked_add(8))
+                .ok_or(())?;
+            // Bounding the offset by the length means the additions below
+            // can't overflow.
+            if fat_arch > bytes.len() {
+                return Err(());
+            }
+            let (offset, size) = if is_64 {
+                (
+                    read_u64(bytes, fat_arch + 8)?,
+                    read_u64(bytes, fat_arch + 16)?,
+                )
+            } else {
+                (
+                    u64::from(read_u32(bytes, fat_arch + 8, true)?),
+                    u64::from(read_u32(bytes, fat_arch + 12, true)?),
+                )
+            };
+
+            let offset = usize::try_from(offset).map_err(|_| ())?;
+            let size = usize::try_from(size).map_err(|_| ())?;
+            let end = offset.checked_add(size).ok_or(())?;
+            triples.push(triple_from_thin_macho(bytes.get(offset..end).ok_or(())?)?);
+        }
+
+        Ok(triples)
+    }
+}
+
+/// Return the triple for a single-architecture Mach-O file.
+fn triple_from_thin_macho(bytes: &[u8]) -> Result<Triple, ()> {
+    // The magic number is written in the file's own byte order.
+    let (big_endian, header_size) = match read_u32(bytes, 0, false)? {
+        MH_MAGIC => (false, 28),
+        MH_MAGIC_64 => (false, 32),
+        MH_CIGAM => (true, 28),
+        MH_CIGAM_64 => (true, 32),
+        _ => return Err(()),
+    };
+    let read = |offset| read_u32(bytes, offset, big_endian);
+
+    let architecture = Architecture::from_macho_cpu(read(4)?, read(8)?)?;
+    let ncmds = read(16)?;
+
+    let mut operating_system = OperatingSystem::Darwin(None);
+    let mut environment = Environment::Unknown;
+    let mut offset: usize = header_size;
+    for _ in 0..ncmds {
+        if offset > bytes.len() {
+            return Err(());
+        }
+        let cmd = read(offset)?;
+        let cmdsize = usize::try_from(read(offset + 4)?).map_err(|_| ())?;
+        if cmdsize < 8 || cmdsize > bytes.len() - offset {
+            return Err(());
+        }
+
+        match cmd {
+            LC_BUILD_VERSION => {
+                let platform = ApplePlatform::from_number(read(offset + 8)?)?;
+                let version = Some(DeploymentTarget::from_macho_u32(read(offset + 12)?));
+                let (os, env) = match platform {
+                    ApplePlatform::MacOS => {
+                        (OperatingSystem::MacOSX(version), Environment::Unknown)
+                    }
+                    ApplePlatform::Ios => (OperatingSystem::IOS(version), Environment::Unknown),
+                    ApplePlatform::TvOS => (OperatingSystem::TvOS(version), Environment::Unknown),
+                    ApplePlatform::WatchOS => {
+                        (OperatingSystem::WatchOS(version), Environment::Unknown)
+                    }
+                    ApplePlatform::BridgeOS => {
+                        (OperatingSystem::BridgeOS(version), Environment::Unknown)
+                    }
+                    ApplePlatform::MacCatalyst => {
+                        (OperatingSystem::IOS(version), Environment::Macabi)
+                    }
+                    ApplePlatform::IosSimulator => {
+                        (OperatingSystem::IOS(version), Environment::Sim)
+                    }
+                    ApplePlatform::TvOSSimulator => {
+                        (OperatingSystem::TvOS(version), Environment::Sim)
+                    }
+                    ApplePlatform::WatchOSSimulator => {
+                        (OperatingSystem::WatchOS(version), Environment::Sim)
+                    }
+                    ApplePlatform::DriverKit => {
+                        (OperatingSystem::DriverKit(version), Environment::Unknown)
+                    }
+                    ApplePlatform::VisionOS => {
+                        (OperatingSystem::VisionOS(version), Environment::Unknown)
+                    }
+                    ApplePlatform::VisionOSSimulator => {
+                        (OperatingSystem::VisionOS(version), Environment::Sim)
+                    }
+                    ApplePlatform::Firmware => {
+                        (OperatingSystem::Firmware(version), Environment::Unknown)
+                    }
+                    ApplePlatform::SepOS => return Err(()),
+                };
+                operating_system = os;
+                environment = env;
+                // `LC_BUILD_VERSION` supersedes the older commands, and if a
+                // binary is built for several platforms, the first is the
+                // primary one.
+                break;
+            }
+            LC_VERSION_MIN_MACOSX
+            | LC_VERSION_MIN_IPHONEOS
+            | LC_VERSION_MIN_TVOS
+            | LC_VERSION_MIN_WATCHOS => {
+                let version = Some(DeploymentTarget::from_macho_u32(read(offset + 8)?));
+                operating_system = match cmd {
+                    LC_VERSION_MIN_MACOSX => OperatingSystem::MacOSX(version),
+                    LC_VERSION_MIN_IPHONEOS => OperatingSystem::IOS(version),
+                    LC_VERSION_MIN_TVOS => OperatingSystem::TvOS(version),
+                    _ => OperatingSystem::WatchOS(version),
+                };
+            }
+            _ => {}
+        }
+
+        offset = offset.checked_add(cmdsize).ok_or(())?;
+    }
+
+    Ok(Triple {
+        architecture,
+        vendor: Vendor::Apple,
+        operating_system,
+        environment,
+        binary_format: BinaryFormat::Macho,
+    })
+}
+
+/// Read a `u32` at `offset`, in the given byte order.
+fn read_u32(bytes: &[u8], offset: usize, big_endian: bool) -> Result<u32, ()> {
+    let end = offset.checked_add(4).ok_or(())?;
+    let b = bytes.get(offset..end).ok_or(())?;
+    let b = [b[0], b[1], b[2], b[3]];
+    Ok(if big_endian {
+        u32::from_be_bytes(b)
+    } else {
+        u32::from_le_bytes(b)
+    })
+}
+
+/// Read a big-endian `u64` at `offset`, as found in fat headers.
+fn read_u64(bytes: &[u8], offset: usize) -> Result<u64, ()> {
+    let high = read_u32(bytes, offset, true)?;
+    let low = read_u32(bytes, offset.checked_add(4).ok_or(())?, true)?;
+    Ok((u64::from(high) << 32) | u64::from(low))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::{String, ToString};
+
+    /// Build a little-endian 64-bit Mach-O header with the given load
+    /// commands, each given as its command and payload.
+    fn thin(cputype: u32, cpusubtype: u32, commands: &[(u32, &[u32])]) -> Vec<u8> {
+        let mut words = vec![
+            MH_MAGIC_64,
+            cputype,
+            cpusubtype,
+            2,
+            commands.len() as u32,
+            0,
+            0,
+            0,
+        ];
+        for (cmd, payload) in commands {
+            words.push(*cmd);
+            words.push(8 + 4 * payload.len() as u32);
+            words.extend_from_slice(payload);
+        }
+        words
+            .iter()
+            .flat_map(|word| word.to_le_bytes().to_vec())
+            .collect()
+    }
+
+    fn triples(bytes: &[u8]) -> Result<Vec<String>, ()> {
+        Triple::from_macho_bytes(bytes)
+            .map(|triples| triples.iter().map(ToString::to_string).collect())
+    }
+
+    #[test]
+    fn thin_macho() {
+        // LC_BUILD_VERSION with macOS 11.0, the SDK version and no tools.
+        let bytes = thin(
+            0x0100_000c,
+            0,
+            &[(LC_BUILD_VERSION, &[1, 0x000b_0000, 0x000e_0000, 0])],
+        );
+        assert_eq!(
+            triples(&bytes),
+            Ok(vec!["aarch64-apple-macosx11.0.0".to_string()])
+        );
+
+        let bytes = thin(
+            0x0100_000c,
+            0x8000_0002,
+            &[(LC_BUILD_VERSION, &[7, 0x000e_0000, 0x0011_0000, 0])],
+        );
+        assert_eq!(
+            triples(&bytes),
+            Ok(vec!["arm64e-apple-ios14.0.0-sim".to_string()])
+        );
+
+        let bytes = thin(
+            0x0100_0007,
+            3,
+            &[(LC_BUILD_VERSION, &[6, 0x000d_0100, 0x0011_0000, 0])],
+        );
+        assert_eq!(
+            triples(&bytes),
+            Ok(vec!["x86_64-apple-ios13.1.0-macabi".to_string()])
+        );
+
+        // Other load commands are skipped over.
+        let bytes = thin(
+            0x0100_0007,
+            8,
+            &[
+                (0x19, &[0; 16]),
+                (LC_VERSION_MIN_MACOSX, &[0x000a_0900, 0x000a_0900]),
+            ],
+        );
+        assert_eq!(
+            triples(&bytes),
+            Ok(vec!["x86_64h-apple-macosx10.9.0".to_string()])
+        );
+
+        let bytes = thin(
+            0x0200_000c,
+            1,
+            &[(LC_VERSION_MIN_WATCHOS, &[0x0005_0000, 0])],
+        );
+        assert_eq!(
+            triples(&bytes),
+            Ok(vec!["arm64_32-apple-watchos5.0.0".to_string()])
+        );
+
+        let bytes = thin(0x0100_0007, 3, &[]);
+        assert_eq!(triples(&bytes), Ok(vec!["x86_64-apple-darwin".to_string()]));
+
+        // A big-endian 32-bit PowerPC file.
+        let mut bytes = Vec::new();
+        for word in &[
+            MH_MAGIC,
+            18,
+            0,
+            2,
+            1,
+            16,
+            0,
+            LC_VERSION_MIN_MACOSX,
+            16,
+            0x000a_0400,
+            0,
+        ] {
+            bytes.extend_from_slice(&u32::to_be_bytes(*word));
+        }
+        assert_eq!(
+            triples(&bytes),
+            Ok(vec!["powerpc-apple-macosx10.4.0".to_string()])
+        );
+    }
+
+    #[test]
+    fn fat_macho() {
+        let x86_64 = thin(
+            0x0100_0007,
+            3,
+            &[(LC_BUILD_VERSION, &[1, 0x000a_0c00, 0, 0])],
+        );
+        let arm64 = thin(
+            0x0100_000c,
+            0,
+            &[(LC_BUILD_VERSION, &[1, 0x000b_0000, 0, 0])],
+        );
+
+        let mut fat = Vec::new();
+        let first = 8 + 2 * 20;
+        let second = first + x86_64.len();
+        for word in &[
+            FAT_MAGIC,
+            2,
+            0x0100_0007,
+            3,
+            first as u32,
+            x86_64.len() as u32,
+            0,
+            0x0100_000c,
+            0,
+            second as u32,
+            arm64.len() as u32,
+            0,
+        ] {
+            fat.extend_from_slice(&word.to_be_bytes());
+        }
+        fat.extend_from_slice(&x86_64);
+        fat.extend_from_slice(&arm64);
+        assert_eq!(
+            triples(&fat),
+            Ok(vec![
+                "x86_64-apple-macosx10.12.0".to_string(),
+                "aarch64-apple-macosx11.0.0".to_string(),
+            ])
+        );
+
+        let mut fat64 = Vec::new();
+        let first = 8 + 32;
+        fat64.extend_from_slice(&FAT_MAGIC_64.to_be_bytes());
+        fat64.extend_from_slice(&1u32.to_be_bytes());
+        fat64.extend_from_slice(&0x0100_000cu32.to_be_bytes());
+        fat64.extend_from_slice(&0u32.to_be_bytes());
+        fat64.extend_from_slice(&(first as u64).to_be_bytes());
+        fat64.extend_from_slice(&(arm64.len() as u64).to_be_bytes());
+        fat64.extend_from_slice(&[0; 8]);
+        fat64.extend_from_slice(&arm64);
+        assert_eq!(
+            triples(&fat64),
+            Ok(vec!["aarch64-apple-macosx11.0.0".to_string()])
+        );
+
+        // A slice that extends past the end of the file.
+        let truncated = &fat[..fat.len() - 1];
+        assert_eq!(triples(truncated), Err(()));
+    }
+
+    #[test]
+    fn invalid_macho() {
+        assert_eq!(triples(&[]), Err(()));
+        assert_eq!(triples(b"\x7fELF\x02\x01\x01\x00"), Err(()));
+
+        let bytes = thin(
+            0x0100_0007,
+            3,
+            &[(LC_BUILD_VERSION, &[1, 0x000a_0c00, 0, 0])],
+        );
+        assert_eq!(triples(&bytes[..bytes.len() - 4]), Err(()));
+
+        // An unknown platform, and an unknown CPU type.
+        let bytes = thin(0x0100_0007, 3, &[(LC_BUILD_VERSION, &[99, 0, 0, 0])]);
+        assert_eq!(triples(&bytes), Err(()));
+        let bytes = thin(0x0100_0017, 0, &[]);
+        assert_eq!(triples(&bytes), Err(()));
+
+        // A load command too small to make progress.
+        let mut bytes = thin(0x0100_0007, 3, &[(0x19, &[])]);
+        bytes[36..40].copy_from_slice(&0u32.to_le_bytes());
+        assert_eq!(triples(&bytes), Err(()));
+    }
+}
@@ -18,15 +18,37 @@ pub enum Architecture {
     Unknown,
     Arm(ArmArchitecture),
     AmdGcn,
+    Arc,
     Aarch64(Aarch64Architecture),
     Asmjs,
     Avr,
-    Bpfeb,
-    Bpfel,
-    Hexagon,
+    /// BPF with the endianness of the host that runs it, as in LLVM's plain
+    /// `bpf`, optionally with the [ISA version]. Its
+    /// [`endianness`](Self::endianness) is that of the machine this code is
+    /// running on.
+    ///
+    /// [ISA version]: BpfVersion
+    Bpf(Option<BpfVersion>),
+    /// Big-endian BPF, optionally with the [ISA version].
+    ///
+    /// [ISA version]: BpfVersion
+    Bpfeb(Option<BpfVersion>),
+    /// Little-endian BPF, optionally with the [ISA version].
+    ///
+    /// [ISA version]: BpfVersion
+    Bpfel(Option<BpfVersion>),
+    Hexagon(HexagonArchitecture),
+    Kalimba,
+    Lanai,
+    /// The PNaCl-style generic little-endian 32-bit architecture.
+    Le32,
+    /// The PNaCl-style generic little-endian 64-bit architecture.
+    Le64,
     X86_32(X86_32Architecture),
     M68k,
     LoongArch64,
+    Microblaze,
+    Microblazeel,
     Mips32(Mips32Architecture),
     Mips64(Mips64Architecture),
     Msp430,
@@ -38,18 +60,29 @@ pub enum Architecture {
     Powerpc,
     Powerpc64,
     Powerpc64le,
+    /// AMD GPUs from HD2XXX to HD6XXX.
+    R600,
     Riscv32(Riscv32Architecture),
     Riscv64(Riscv64Architecture),
     S390x,
+    /// Movidius SHAVE vector processors.
+    Shave,
     Sparc,
     Sparc64,
+    Sparcel,
     Sparcv9,
+    /// The TCE (<http://tce.cs.tut.fi/>) processor, big-endian.
+    Tce,
+    /// The TCE (<http://tce.cs.tut.fi/>) processor, little-endian.
+    Tcele,
+    /// NEC SX-Aurora Vector Engine.
+    Ve,
     Wasm32,
     Wasm64,
     X86_64,
     /// x86_64 target that only supports Haswell-compatible Intel chips.
     X86_64h,
-    XTensa,
+    XTensa(XtensaArchitecture),
     Clever(CleverArchitecture),
     /// A software machine that produces zero-knowledge proofs of the execution.
     ///
@@ -116,30 +149,89 @@ pub enum ArmArchitecture {
 pub enum Aarch64Architecture {
     Aarch64,
     Aarch64be,
+    /// Apple's AArch64 with pointer authentication.
+    Arm64e,
+    /// Apple's AArch64 with 32-bit pointers, used on watchOS.
+    Arm64_32,
+    /// The "emulation compatible" ABI used to mix native AArch64 code with
+    /// emulated x86-64 code on Windows.
+    Arm64ec,
 }
 
-// #[cfg_attr(feature = "rust_1_40", non_exhaustive)]
-// #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
-// #[allow(missing_docs)]
-// pub enum ArmFpu {
-//     Vfp,
-//     Vfpv2,
-//     Vfpv3,
-//     Vfpv3Fp16,
-//     Vfpv3Xd,
-//     Vfpv3XdFp16,
-//     Neon,
-//     NeonVfpv3,
-//     NeonVfpv4,
-//     Vfpv4,
-//     Vfpv4D16,
-//     Fpv4SpD16,
-//     Fpv5SpD16,
-//     Fpv5D16,
-//     FpArmv8,
-//     NeonFpArmv8,
-//     CryptoNeonFpArmv8,
-// }
+/// An ARM floating-point unit, named as in GCC's and Clang's `-mfpu` option.
+#[cfg_attr(feature = "rust_1_40", non_exhaustive)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum ArmFpu {
+    Vfp,
+    Vfpv2,
+    Vfpv3,
+    Vfpv3Fp16,
+    Vfpv3D16,
+    Vfpv3Xd,
+    Vfpv3XdFp16,
+    Neon,
+    NeonVfpv3,
+    NeonVfpv4,
+    Vfpv4,
+    Vfpv4D16,
+    Fpv4SpD16,
+    Fpv5SpD16,
+    Fpv5D16,
+    FpArmv8,
+    NeonFpArmv8,
+    CryptoNeonFpArmv8,
+}
+
+impl ArmFpu {
+    /// Test if this FPU includes the NEON (Advanced SIMD) extension.
+    pub fn has_neon(self) -> bool {
+        use ArmFpu::*;
+
+        match self {
+            Neon | NeonVfpv3 | NeonVfpv4 | NeonFpArmv8 | CryptoNeonFpArmv8 => true,
+            Vfp | Vfpv2 | Vfpv3 | Vfpv3Fp16 | Vfpv3D16 | Vfpv3Xd | Vfpv3XdFp16 | Vfpv4
+            | Vfpv4D16 | Fpv4SpD16 | Fpv5SpD16 | Fpv5D16 | FpArmv8 => false,
+        }
+    }
+
+    /// Test if this FPU only supports single-precision arithmetic.
+    pub fn is_single_precision_only(self) -> bool {
+        use ArmFpu::*;
+
+        match self {
+            Vfpv3Xd | Vfpv3XdFp16 | Fpv4SpD16 | Fpv5SpD16 => true,
+            Vfp | Vfpv2 | Vfpv3 | Vfpv3Fp16 | Vfpv3D16 | Neon | NeonVfpv3 | NeonVfpv4 | Vfpv4
+            | Vfpv4D16 | Fpv5D16 | FpArmv8 | NeonFpArmv8 | CryptoNeonFpArmv8 => false,
+        }
+    }
+
+    /// Convert into a string
+    pub fn into_str(self) -> Cow<'static, str> {
+        use ArmFpu::*;
+
+        match self {
+            Vfp => Cow::Borrowed("vfp"),
+            Vfpv2 => Cow::Borrowed("vfpv2"),
+            Vfpv3 => Cow::Borrowed("vfpv3"),
+            Vfpv3Fp16 => Cow::Borrowed("vfpv3-fp16"),
+            Vfpv3D16 => Cow::Borrowed("vfpv3-d16"),
+            Vfpv3Xd => Cow::Borrowed("vfpv3xd"),
+            Vfpv3XdFp16 => Cow::Borrowed("vfpv3xd-fp16"),
+            Neon => Cow::Borrowed("neon"),
+            NeonVfpv3 => Cow::Borrowed("neon-vfpv3"),
+            NeonVfpv4 => Cow::Borrowed("neon-vfpv4"),
+            Vfpv4 => Cow::Borrowed("vfpv4"),
+            Vfpv4D16 => Cow::Borrowed("vfpv4-d16"),
+            Fpv4SpD16 => Cow::Borrowed("fpv4-sp-d16"),
+            Fpv5SpD16 => Cow::Borrowed("fpv5-sp-d16"),
+            Fpv5D16 => Cow::Borrowed("fpv5-d16"),
+            FpArmv8 => Cow::Borrowed("fp-armv8"),
+            NeonFpArmv8 => Cow::Borrowed("neon-fp-armv8"),
+            CryptoNeonFpArmv8 => Cow::Borrowed("crypto-neon-fp-armv8"),
+        }
+    }
+}
 
 impl ArmArchitecture {
     /// Test if this architecture uses the Thumb instruction set.
@@ -193,9 +285,64 @@ impl ArmArchitecture {
         }
     }
 
-    // pub fn has_fpu(self) -> Result<&'static [ArmFpu], ()> {
+    /// Return the FPUs which may be paired with this architecture, or
+    /// `Err(())` if the architecture is too generic to tell.
+    ///
+    /// An empty slice means that the architecture has no FPU.
+    pub fn has_fpu(self) -> Result<&'static [ArmFpu], ()> {
+        use ArmArchitecture::*;
+
+        match self {
+            Arm | Armeb | Thumbeb => Err(()),
+            Armv4 | Armv4t | Thumbv4t | Armv6m | Thumbv6m | Armv7m | Thumbv7m | Armv8mBase
+            | Thumbv8mBase => Ok(&[]),
+            Armv5t | Armv5te | Armv5tej | Thumbv5te | Armv6 | Armv6j | Armv6k | Armv6z
+            | Armv6kz | Armv6t2 => Ok(&[ArmFpu::Vfp, ArmFpu::Vfpv2]),
+            Armv7 | Armv7a | Thumbv7a => Ok(&[
+                ArmFpu::Vfpv3,
+                ArmFpu::Vfpv3Fp16,
+                ArmFpu::Vfpv3D16,
+                ArmFpu::Neon,
+                ArmFpu::NeonVfpv3,
+                ArmFpu::Vfpv4,
+                ArmFpu::Vfpv4D16,
+                ArmFpu::NeonVfpv4,
+            ]),
+            Armv7ve => Ok(&[ArmFpu::Vfpv4, ArmFpu::Vfpv4D16, ArmFpu::NeonVfpv4]),
+            Armv7s | Armv7k => Ok(&[ArmFpu::NeonVfpv4]),
+            Thumbv7neon => Ok(&[ArmFpu::Neon, ArmFpu::NeonVfpv3, ArmFpu::NeonVfpv4]),
+            Armv7r | Armebv7r => Ok(&[ArmFpu::Vfpv3D16, ArmFpu::Vfpv3Xd, ArmFpu::Vfpv3XdFp16]),
+            Thumbv7em => Ok(&[ArmFpu::Fpv4SpD16, ArmFpu::Fpv5SpD16, ArmFpu::Fpv5D16]),
+            Armv8mMain | Thumbv8mMain => Ok(&[ArmFpu::Fpv5SpD16, ArmFpu::Fpv5D16]),
+            Armv8r => Ok(&[
+                ArmFpu::Fpv5SpD16,
+                ArmFpu::Fpv5D16,
+                ArmFpu::FpArmv8,
+                ArmFpu::NeonFpArmv8,
+            ]),
+            Armv8 | Armv8a | Armv8_1a | Armv8_2a | Armv8_3a | Armv8_4a | Armv8_5a => Ok(&[
+                ArmFpu::FpArmv8,
+                ArmFpu::NeonFpArmv8,
+                ArmFpu::CryptoNeonFpArmv8,
+            ]),
+        }
+    }
+
+    /// Test if this architecture guarantees the NEON (Advanced SIMD)
+    /// extension.
+    pub fn has_neon(self) -> bool {
+        use ArmArchitecture::*;
 
-    // }
+        match self {
+            Armv7s | Armv7k | Thumbv7neon | Armv8 | Armv8a | Armv8_1a | Armv8_2a | Armv8_3a
+            | Armv8_4a | Armv8_5a => true,
+            Arm | Armeb | Armv4 | Armv4t | Armv5t | Armv5te | Armv5tej | Armv6 | Armv6j
+            | Armv6k | Armv6z | Armv6kz | Armv6t2 | Armv6m | Armv7 | Armv7a | Armv7ve | Armv7m
+            | Armv7r | Armv8mBase | Armv8mMain | Armv8r | Armebv7r | Thumbeb | Thumbv4t
+            | Thumbv5te | Thumbv6m | Thumbv7a | Thumbv7em | Thumbv7m | Thumbv8mBase
+            | Thumbv8mMain => false,
+        }
+    }
 
     /// Return the pointer bit width of this target's architecture.
     #[rustfmt::skip]
@@ -352,28 +499,50 @@ impl Aarch64Architecture {
     /// Test if this architecture uses the Thumb instruction set.
     pub fn is_thumb(self) -> bool {
         match self {
-            Aarch64Architecture::Aarch64 | Aarch64Architecture::Aarch64be => false,
+            Aarch64Architecture::Aarch64
+            | Aarch64Architecture::Aarch64be
+            | Aarch64Architecture::Arm64e
+            | Aarch64Architecture::Arm64_32
+            | Aarch64Architecture::Arm64ec => false,
         }
     }
 
-    // pub fn has_fpu(self) -> Result<&'static [ArmFpu], ()> {
-
-    // }
+    /// Return the FPUs which may be paired with this architecture.
+    ///
+    /// Floating-point and NEON are mandatory in AArch64, so this always
+    /// includes NEON.
+    pub fn has_fpu(self) -> Result<&'static [ArmFpu], ()> {
+        match self {
+            Aarch64Architecture::Aarch64
+            | Aarch64Architecture::Aarch64be
+            | Aarch64Architecture::Arm64e
+            | Aarch64Architecture::Arm64_32
+            | Aarch64Architecture::Arm64ec => Ok(&[ArmFpu::NeonFpArmv8, ArmFpu::CryptoNeonFpArmv8]),
+        }
+    }
 
     /// Return the pointer bit width of this target's architecture.
     ///
     /// This function is only aware of the CPU architecture so it is not aware
-    /// of ilp32 ABIs.
+    /// of ilp32 ABIs, except for `arm64_32`, which is named as an
+    /// architecture.
     pub fn pointer_width(self) -> PointerWidth {
         match self {
-            Aarch64Architecture::Aarch64 | Aarch64Architecture::Aarch64be => PointerWidth::U64,
+            Aarch64Architecture::Aarch64
+            | Aarch64Architecture::Aarch64be
+            | Aarch64Architecture::Arm64e
+            | Aarch64Architecture::Arm64ec => PointerWidth::U64,
+            Aarch64Architecture::Arm64_32 => PointerWidth::U32,
         }
     }
 
     /// Return the endianness of this architecture.
     pub fn endianness(self) -> Endianness {
         match self {
-            Aarch64Architecture::Aarch64 => Endianness::Little,
+            Aarch64Architecture::Aarch64
+            | Aarch64Architecture::Arm64e
+            | Aarch64Architecture::Arm64_32
+            | Aarch64Architecture::Arm64ec => Endianness::Little,
             Aarch64Architecture::Aarch64be => Endianness::Big,
         }
     }
@@ -385,6 +554,9 @@ impl Aarch64Architecture {
         match self {
             Aarch64 => Cow::Borrowed("aarch64"),
             Aarch64be => Cow::Borrowed("aarch64_be"),
+            Arm64e => Cow::Borrowed("arm64e"),
+            Arm64_32 => Cow::Borrowed("arm64_32"),
+            Arm64ec => Cow::Borrowed("arm64ec"),
         }
     }
 }
@@ -409,6 +581,213 @@ impl CleverArchitecture {
     }
 }
 
+/// An enum for all Hexagon DSP architecture versions.
+#[cfg_attr(feature = "rust_1_40", non_exhaustive)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum HexagonArchitecture {
+    Hexagon, // Generic hexagon
+    Hexagonv60,
+    Hexagonv62,
+    Hexagonv65,
+    Hexagonv66,
+    Hexagonv67,
+    /// The "tiny core" variant of v67, which has no HVX unit.
+    Hexagonv67t,
+    Hexagonv68,
+    Hexagonv69,
+    Hexagonv71,
+    /// The "tiny core" variant of v71, which has no HVX unit.
+    Hexagonv71t,
+    Hexagonv73,
+    Hexagonv75,
+    Hexagonv79,
+}
+
+impl HexagonArchitecture {
+    /// Return the ISA version number, such as `68` for `hexagonv68`, or
+    /// `None` for the generic architecture.
+    pub fn version(self) -> Option<u8> {
+        use HexagonArchitecture::*;
+
+        match self {
+            Hexagon => None,
+            Hexagonv60 => Some(60),
+            Hexagonv62 => Some(62),
+            Hexagonv65 => Some(65),
+            Hexagonv66 => Some(66),
+            Hexagonv67 | Hexagonv67t => Some(67),
+            Hexagonv68 => Some(68),
+            Hexagonv69 => Some(69),
+            Hexagonv71 | Hexagonv71t => Some(71),
+            Hexagonv73 => Some(73),
+            Hexagonv75 => Some(75),
+            Hexagonv79 => Some(79),
+        }
+    }
+
+    /// Test if this architecture version has the Hexagon Vector eXtensions
+    /// (HVX) coprocessor.
+    pub fn has_hvx(self) -> bool {
+        !self.hvx_vector_lengths().is_empty()
+    }
+
+    /// Return the HVX vector lengths, in bytes, that this architecture
+    /// version supports.
+    ///
+    /// The 64-byte mode was dropped in v73, and the tiny cores have no HVX at
+    /// all. The generic architecture makes no guarantees, so this is empty.
+    pub fn hvx_vector_lengths(self) -> &'static [u16] {
+        use HexagonArchitecture::*;
+
+        match self {
+            Hexagon | Hexagonv67t | Hexagonv71t => &[],
+            Hexagonv60 | Hexagonv62 | Hexagonv65 | Hexagonv66 | Hexagonv67 | Hexagonv68
+            | Hexagonv69 | Hexagonv71 => &[64, 128],
+            Hexagonv73 | Hexagonv75 | Hexagonv79 => &[128],
+        }
+    }
+
+    /// Parse a CPU name as accepted by `-mcpu`, such as `hexagonv68`.
+    ///
+    /// The version may also be given on its own, as in `v68`.
+    pub fn from_cpu(cpu: &str) -> Result<Self, ()> {
+        if cpu.starts_with('v') {
+            Self::from_str(&format!("hexagon{}", cpu))
+        } else {
+            Self::from_str(cpu)
+        }
+    }
+
+    /// Convert into a string
+    pub fn into_str(self) -> Cow<'static, str> {
+        use HexagonArchitecture::*;
+
+        match self {
+            Hexagon => Cow::Borrowed("hexagon"),
+            Hexagonv60 => Cow::Borrowed("hexagonv60"),
+            Hexagonv62 => Cow::Borrowed("hexagonv62"),
+            Hexagonv65 => Cow::Borrowed("hexagonv65"),
+            Hexagonv66 => Cow::Borrowed("hexagonv66"),
+            Hexagonv67 => Cow::Borrowed("hexagonv67"),
+            Hexagonv67t => Cow::Borrowed("hexagonv67t"),
+            Hexagonv68 => Cow::Borrowed("hexagonv68"),
+            Hexagonv69 => Cow::Borrowed("hexagonv69"),
+            Hexagonv71 => Cow::Borrowed("hexagonv71"),
+            Hexagonv71t => Cow::Borrowed("hexagonv71t"),
+            Hexagonv73 => Cow::Borrowed("hexagonv73"),
+            Hexagonv75 => Cow::Borrowed("hexagonv75"),
+            Hexagonv79 => Cow::Borrowed("hexagonv79"),
+        }
+    }
+}
+
+/// An enum for Xtensa cores.
+///
+/// `rustc` spells the core in the vendor position, as in
+/// `xtensa-esp32-none-elf`, so the core is printed there instead of the
+/// vendor, and the vendor of such triples is `Vendor::Espressif`.
+///
+/// Printing an `Architecture` on its own, or a `Triple` with any other
+/// vendor, can't show the core, so it is printed as plain `xtensa` and the
+/// core is lost.
+#[cfg_attr(feature = "rust_1_40", non_exhaustive)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum XtensaArchitecture {
+    Xtensa, // Generic xtensa
+    Esp32,
+    Esp32s2,
+    Esp32s3,
+}
+
+impl XtensaArchitecture {
+    /// Test if this core has a (single-precision) floating-point unit.
+    pub fn has_fpu(self) -> bool {
+        use XtensaArchitecture::*;
+
+        match self {
+            Esp32 | Esp32s3 => true,
+            Xtensa | Esp32s2 => false,
+        }
+    }
+
+    /// Test if this core has the windowed register option, and so uses the
+    /// windowed (`call8`) ABI rather than the `call0` ABI.
+    pub fn has_windowed_registers(self) -> bool {
+        use XtensaArchitecture::*;
+
+        match self {
+            Esp32 | Esp32s2 | Esp32s3 => true,
+            Xtensa => false,
+        }
+    }
+
+    /// Convert into a string
+    pub fn into_str(self) -> Cow<'static, str> {
+        use XtensaArchitecture::*;
+
+        match self {
+            Xtensa => Cow::Borrowed("xtensa"),
+            Esp32 => Cow::Borrowed("esp32"),
+            Esp32s2 => Cow::Borrowed("esp32s2"),
+            Esp32s3 => Cow::Borrowed("esp32s3"),
+        }
+    }
+}
+
+/// A BPF instruction set version, as selected by LLVM's `-mcpu=vN`.
+///
+/// Versions are ordered, and each one is a superset of the previous one.
+#[cfg_attr(feature = "rust_1_40", non_exhaustive)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[allow(missing_docs)]
+pub enum BpfVersion {
+    V1,
+    V2,
+    V3,
+    V4,
+}
+
+impl BpfVersion {
+    /// Test if this version has the `JLT`, `JLE`, `JSLT` and `JSLE`
+    /// conditional jumps, introduced in v2.
+    pub fn supports_extended_jumps(self) -> bool {
+        self >= BpfVersion::V2
+    }
+
+    /// Test if this version has the `JMP32` instruction class and 32-bit
+    /// ALU subregisters, introduced in v3.
+    pub fn supports_jmp32(self) -> bool {
+        self >= BpfVersion::V3
+    }
+
+    /// Test if this version has atomic read-modify-write instructions
+    /// beyond `XADD`, introduced in v3.
+    pub fn supports_atomics(self) -> bool {
+        self >= BpfVersion::V3
+    }
+
+    /// Test if this version has sign-extending loads and moves, signed
+    /// division and modulo, `BSWAP` and 32-bit unconditional jumps,
+    /// introduced in v4.
+    pub fn supports_signed_loads(self) -> bool {
+        self >= BpfVersion::V4
+    }
+
+    /// Convert into a string
+    pub fn into_str(self) -> Cow<'static, str> {
+        use BpfVersion::*;
+
+        match self {
+            V1 => Cow::Borrowed("v1"),
+            V2 => Cow::Borrowed("v2"),
+            V3 => Cow::Borrowed("v3"),
+            V4 => Cow::Borrowed("v4"),
+        }
+    }
+}
+
 /// An enum for all 32-bit RISC-V architectures.
 #[cfg_attr(feature = "rust_1_40", non_exhaustive)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
@@ -422,6 +801,9 @@ pub enum Riscv32Architecture {
     Riscv32imac,
     Riscv32imafc,
     Riscv32imc,
+    Riscv32e,
+    Riscv32em,
+    Riscv32emc,
 }
 
 impl Riscv32Architecture {
@@ -438,6 +820,9 @@ impl Riscv32Architecture {
             Riscv32imac => Cow::Borrowed("riscv32imac"),
             Riscv32imafc => Cow::Borrowed("riscv32imafc"),
             Riscv32imc => Cow::Borrowed("riscv32imc"),
+            Riscv32e => Cow::Borrowed("riscv32e"),
+            Riscv32em => Cow::Borrowed("riscv32em"),
+            Riscv32emc => Cow::Borrowed("riscv32emc"),
         }
     }
 }
@@ -467,6 +852,67 @@ impl Riscv64Architecture {
     }
 }
 
+/// A RISC-V integer and floating-point calling convention, as named by the
+/// RISC-V ELF psABI and passed to `-mabi`.
+#[cfg_attr(feature = "rust_1_40", non_exhaustive)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RiscvAbi {
+    /// 32-bit pointers, no floating-point arguments in registers.
+    Ilp32,
+    /// 32-bit pointers, single-precision floating-point arguments in registers.
+    Ilp32f,
+    /// 32-bit pointers, double-precision floating-point arguments in registers.
+    Ilp32d,
+    /// 32-bit pointers on the reduced RV32E register file.
+    Ilp32e,
+    /// 64-bit pointers, no floating-point arguments in registers.
+    Lp64,
+    /// 64-bit pointers, single-precision floating-point arguments in registers.
+    Lp64f,
+    /// 64-bit pointers, double-precision floating-point arguments in registers.
+    Lp64d,
+}
+
+impl RiscvAbi {
+    /// Return the width in bits of the floating-point registers used to pass
+    /// arguments, or `None` if floating-point values are passed in integer
+    /// registers.
+    pub fn float_abi_bits(self) -> Option<u8> {
+        use RiscvAbi::*;
+
+        match self {
+            Ilp32 | Ilp32e | Lp64 => None,
+            Ilp32f | Lp64f => Some(32),
+            Ilp32d | Lp64d => Some(64),
+        }
+    }
+
+    /// Return the pointer bit width implied by this ABI.
+    pub fn pointer_width(self) -> PointerWidth {
+        use RiscvAbi::*;
+
+        match self {
+            Ilp32 | Ilp32f | Ilp32d | Ilp32e => PointerWidth::U32,
+            Lp64 | Lp64f | Lp64d => PointerWidth::U64,
+        }
+    }
+
+    /// Convert into a string
+    pub fn into_str(self) -> Cow<'static, str> {
+        use RiscvAbi::*;
+
+        match self {
+            Ilp32 => Cow::Borrowed("ilp32"),
+            Ilp32f => Cow::Borrowed("ilp32f"),
+            Ilp32d => Cow::Borrowed("ilp32d"),
+            Ilp32e => Cow::Borrowed("ilp32e"),
+            Lp64 => Cow::Borrowed("lp64"),
+            Lp64f => Cow::Borrowed("lp64f"),
+            Lp64d => Cow::Borrowed("lp64d"),
+        }
+    }
+}
+
 /// An enum for all 32-bit x86 architectures.
 #[cfg_attr(feature = "rust_1_40", non_exhaustive)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
@@ -628,8 +1074,14 @@ pub enum Vendor {
     Nvidia,
     Pc,
     Rumprun,
+    /// Sony Computer Entertainment Inc., used for PlayStation triples.
+    Scei,
+    /// Sony Interactive Entertainment, used for newer PlayStation triples.
+    Sie,
     Sun,
     Uwp,
+    /// Used by `rustc` for Windows targets that still support Windows 7.
+    Win7,
     Wrs,
 
     /// A custom vendor. "Custom" in this context means that the vendor is
@@ -660,8 +1112,11 @@ impl Vendor {
             Nvidia => "nvidia",
             Pc => "pc",
             Rumprun => "rumprun",
+            Scei => "scei",
+            Sie => "sie",
             Sun => "sun",
             Uwp => "uwp",
+            Win7 => "win7",
             Wrs => "wrs",
             Custom(name) => name.as_str(),
         }
@@ -670,9 +1125,13 @@ impl Vendor {
 
 /// The minimum OS version that we're compiling for.
 ///
-/// This is formatted as `"major.minor.patch"`.
+/// This is formatted as `"major.minor.patch"`. The BSD and Solaris releases
+/// are formatted without trailing zero components, as in `"13.2"` or `"9"`.
 ///
 /// The size of the parts here are limited by Mach-O's `LC_BUILD_VERSION`.
+///
+/// Deployment targets are ordered by version, so ranges of them such as
+/// `min..=max` can be checked with `contains`.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[allow(missing_docs)]
 pub struct DeploymentTarget {
@@ -681,12 +1140,199 @@ pub struct DeploymentTarget {
     pub patch: u8,
 }
 
+impl DeploymentTarget {
+    /// Create a deployment target from its parts.
+    pub const fn new(major: u16, minor: u8, patch: u8) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Create a deployment target from its parts, or return `Err(())` if they
+    /// don't fit in the sizes that Mach-O allows.
+    pub fn checked_new(major: u32, minor: u32, patch: u32) -> Result<Self, ()> {
+        if major > 0xffff || minor > 0xff || patch > 0xff {
+            return Err(());
+        }
+
+        Ok(Self::new(major as u16, minor as u8, patch as u8))
+    }
+
+    /// Encode this version the way Mach-O load commands do, as the nibbles
+    /// `xxxx.yy.zz`.
+    pub fn to_macho_u32(self) -> u32 {
+        (u32::from(self.major) << 16) | (u32::from(self.minor) << 8) | u32::from(self.patch)
+    }
+
+    /// Decode a version encoded the way Mach-O load commands do, as the
+    /// nibbles `xxxx.yy.zz`.
+    pub fn from_macho_u32(version: u32) -> Self {
+        Self::new((version >> 16) as u16, (version >> 8) as u8, version as u8)
+    }
+}
+
+impl fmt::Display for DeploymentTarget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+impl FromStr for DeploymentTarget {
+    type Err = ();
+
+    /// Parse a `"major.minor.patch"` version, where the minor and patch
+    /// versions may be omitted.
+    fn from_str(s: &str) -> Result<Self, ()> {
+        let mut parts = s.split('.');
+
+        let major = if let Some(part) = parts.next() {
+            parse_version_part(part)?
+        } else {
+            // If the string was just `.`, with no major version, that's
+            // clearly an error.
+            return Err(());
+        };
+        let minor = if let Some(part) = parts.next() {
+            parse_version_part(part)?
+        } else {
+            // Fall back to 0 if no minor version was set
+            0
+        };
+        let patch = if let Some(part) = parts.next() {
+            parse_version_part(part)?
+        } else {
+            // Fall back to 0 if no patch version was set
+            0
+        };
+
+        if parts.next().is_some() {
+            // Too many parts
+            return Err(());
+        }
+
+        Ok(Self::new(major, minor, patch))
+    }
+}
+
+/// The release of a BSD or Solaris operating system, as in `freebsd14.0`.
+///
+/// This remembers how many components the release was written with, so that
+/// it's displayed the way it was parsed; `freebsd14` and `freebsd14.0` name
+/// the same release, but are different triples.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OsRelease {
+    /// The release's version.
+    pub version: DeploymentTarget,
+    /// How many of the major, minor and patch versions are written, from 1
+    /// to 3. Nonzero components are always written.
+    pub components: u8,
+}
+
+impl fmt::Display for OsRelease {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let DeploymentTarget {
+            major,
+            minor,
+            patch,
+        } = self.version;
+
+        write!(f, "{}", major)?;
+        if self.components >= 2 || minor != 0 || patch != 0 {
+            write!(f, ".{}", minor)?;
+        }
+        if self.components >= 3 || patch != 0 {
+            write!(f, ".{}", patch)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for OsRelease {
+    type Err = ();
+
+    /// Parse a `"major.minor.patch"` release, where the minor and patch
+    /// versions may be omitted.
+    fn from_str(s: &str) -> Result<Self, ()> {
+        Ok(Self {
+            version: s.parse()?,
+            // `DeploymentTarget` rejects more than three components.
+            components: s.split('.').count() as u8,
+        })
+    }
+}
+
+/// Parse one component of a version number, accepting only plain decimal
+/// digits, without a sign.
+fn parse_version_part<T: FromStr>(part: &str) -> Result<T, ()> {
+    if !part.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(());
+    }
+    part.parse().map_err(|_| ())
+}
+
+/// The minimum C library version that we're linking against, as in
+/// `x86_64-unknown-linux-gnu.2.17`.
+///
+/// This is formatted as `"major.minor"`, followed by `".patch"` when the
+/// patch version is nonzero.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[allow(missing_docs)]
+pub struct LibcVersion {
+    pub major: u16,
+    pub minor: u16,
+    pub patch: u16,
+}
+
+impl fmt::Display for LibcVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)?;
+        if self.patch != 0 {
+            write!(f, ".{}", self.patch)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for LibcVersion {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, ()> {
+        let mut parts = s.split('.');
+
+        // Unlike Apple deployment targets, a libc version always has at
+        // least a major and a minor version.
+        let major = parse_version_part(parts.next().ok_or(())?)?;
+        let minor = parse_version_part(parts.next().ok_or(())?)?;
+        let patch = if let Some(part) = parts.next() {
+            parse_version_part(part)?
+        } else {
+            0
+        };
+
+        if parts.next().is_some() {
+            // Too many parts
+            return Err(());
+        }
+
+        Ok(Self {
+            major,
+            minor,
+            patch,
+        })
+    }
+}
+
 /// The "operating system" field, which sometimes implies an environment, and
 /// sometimes isn't an actual operating system.
 ///
-/// LLVM's Apple triples may optionally include the [deployment target].
+/// LLVM's Apple triples may optionally include the [deployment target], and
+/// its BSD and Solaris triples may include the [OS release] in the same way,
+/// as in `x86_64-unknown-freebsd13.2`.
 ///
 /// [deployment target]: DeploymentTarget
+/// [OS release]: OsRelease
 #[cfg_attr(feature = "rust_1_40", non_exhaustive)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 #[allow(missing_docs)]
@@ -695,6 +1341,8 @@ pub enum OperatingSystem {
     Aix,
     AmdHsa,
     Bitrig,
+    /// Apple's bridgeOS, which runs on the T2 security chip.
+    BridgeOS(Option<DeploymentTarget>),
     Cloudabi,
     Cuda,
     Cygwin,
@@ -709,10 +1357,15 @@ pub enum OperatingSystem {
     ///
     /// [darwin-wiki]: https://en.wikipedia.org/wiki/Darwin_(operating_system)
     Darwin(Option<DeploymentTarget>),
-    Dragonfly,
+    Dragonfly(Option<OsRelease>),
+    /// Apple's DriverKit, for user-space drivers.
+    DriverKit(Option<DeploymentTarget>),
     Emscripten,
     Espidf,
-    Freebsd,
+    /// Apple's firmware platform.
+    Firmware(Option<DeploymentTarget>),
+    Freebsd(Option<OsRelease>),
+    Freertos,
     Fuchsia,
     Haiku,
     Hermit,
@@ -722,19 +1375,36 @@ pub enum OperatingSystem {
     IOS(Option<DeploymentTarget>),
     L4re,
     Linux,
+    /// Huawei LiteOS.
+    Liteos,
+    /// The PlayStation 3 "Lv2" kernel. Its PowerPC64 ABI uses 32-bit
+    /// pointers.
+    Lv2,
+    Managarm,
     /// macOS.
     ///
     /// WARNING: This does _not_ match the macOS triples when parsing `rustc`
     /// target triples, for that see the [`darwin`](Self::Darwin) OS name.
     MacOSX(Option<DeploymentTarget>),
     Nebulet,
-    Netbsd,
+    Netbsd(Option<OsRelease>),
     None_,
-    Openbsd,
+    /// Apache NuttX.
+    Nuttx,
+    Openbsd(Option<OsRelease>),
+    /// The PlayStation 4 OS. Its C ABI is the x86-64 System V one, except
+    /// that `long double` is 64 bits, the same as `double`.
+    Ps4,
+    /// The PlayStation 5 OS, which has the same C ABI as the PlayStation 4.
+    Ps5,
     Psp,
     Redox,
-    Solaris,
+    Rtems,
+    /// SerenityOS.
+    Serenity,
+    Solaris(Option<OsRelease>),
     SolidAsp3,
+    Theseus,
     TvOS(Option<DeploymentTarget>),
     Uefi,
     VisionOS(Option<DeploymentTarget>),
@@ -742,10 +1412,14 @@ pub enum OperatingSystem {
     Wasi,
     WasiP1,
     WasiP2,
+    WasiP3,
     WatchOS(Option<DeploymentTarget>),
     Windows,
     /// An alternate name for [visionOS][Self::VisionOS].
     XROS(Option<DeploymentTarget>),
+    Zephyr,
+    /// IBM z/OS.
+    Zos,
 }
 
 impl OperatingSystem {
@@ -753,14 +1427,17 @@ impl OperatingSystem {
     pub fn into_str(self) -> Cow<'static, str> {
         use OperatingSystem::*;
 
-        let darwin_version = |name, deployment_target| {
-            if let Some(DeploymentTarget {
-                major,
-                minor,
-                patch,
-            }) = deployment_target
-            {
-                Cow::Owned(format!("{}{}.{}.{}", name, major, minor, patch))
+        let darwin_version = |name, deployment_target: Option<DeploymentTarget>| {
+            if let Some(deployment_target) = deployment_target {
+                Cow::Owned(format!("{}{}", name, deployment_target))
+            } else {
+                Cow::Borrowed(name)
+            }
+        };
+
+        let with_release = |name, release: Option<OsRelease>| {
+            if let Some(release) = release {
+                Cow::Owned(format!("{}{}", name, release))
             } else {
                 Cow::Borrowed(name)
             }
@@ -771,14 +1448,18 @@ impl OperatingSystem {
             Aix => Cow::Borrowed("aix"),
             AmdHsa => Cow::Borrowed("amdhsa"),
             Bitrig => Cow::Borrowed("bitrig"),
+            BridgeOS(deployment_target) => darwin_version("bridgeos", deployment_target),
             Cloudabi => Cow::Borrowed("cloudabi"),
             Cuda => Cow::Borrowed("cuda"),
             Cygwin => Cow::Borrowed("cygwin"),
             Darwin(deployment_target) => darwin_version("darwin", deployment_target),
-            Dragonfly => Cow::Borrowed("dragonfly"),
+            Dragonfly(release) => with_release("dragonfly", release),
+            DriverKit(deployment_target) => darwin_version("driverkit", deployment_target),
             Emscripten => Cow::Borrowed("emscripten"),
             Espidf => Cow::Borrowed("espidf"),
-            Freebsd => Cow::Borrowed("freebsd"),
+            Firmware(deployment_target) => darwin_version("firmware", deployment_target),
+            Freebsd(release) => with_release("freebsd", release),
+            Freertos => Cow::Borrowed("freertos"),
             Fuchsia => Cow::Borrowed("fuchsia"),
             Haiku => Cow::Borrowed("haiku"),
             Hermit => Cow::Borrowed("hermit"),
@@ -788,15 +1469,24 @@ impl OperatingSystem {
             IOS(deployment_target) => darwin_version("ios", deployment_target),
             L4re => Cow::Borrowed("l4re"),
             Linux => Cow::Borrowed("linux"),
+            Liteos => Cow::Borrowed("liteos"),
+            Lv2 => Cow::Borrowed("lv2"),
+            Managarm => Cow::Borrowed("managarm"),
             MacOSX(deployment_target) => darwin_version("macosx", deployment_target),
             Nebulet => Cow::Borrowed("nebulet"),
-            Netbsd => Cow::Borrowed("netbsd"),
+            Netbsd(release) => with_release("netbsd", release),
             None_ => Cow::Borrowed("none"),
-            Openbsd => Cow::Borrowed("openbsd"),
+            Nuttx => Cow::Borrowed("nuttx"),
+            Openbsd(release) => with_release("openbsd", release),
+            Ps4 => Cow::Borrowed("ps4"),
+            Ps5 => Cow::Borrowed("ps5"),
             Psp => Cow::Borrowed("psp"),
             Redox => Cow::Borrowed("redox"),
-            Solaris => Cow::Borrowed("solaris"),
+            Rtems => Cow::Borrowed("rtems"),
+            Serenity => Cow::Borrowed("serenity"),
+            Solaris(release) => with_release("solaris", release),
             SolidAsp3 => Cow::Borrowed("solid_asp3"),
+            Theseus => Cow::Borrowed("theseus"),
             TvOS(deployment_target) => darwin_version("tvos", deployment_target),
             Uefi => Cow::Borrowed("uefi"),
             VxWorks => Cow::Borrowed("vxworks"),
@@ -804,16 +1494,22 @@ impl OperatingSystem {
             Wasi => Cow::Borrowed("wasi"),
             WasiP1 => Cow::Borrowed("wasip1"),
             WasiP2 => Cow::Borrowed("wasip2"),
+            WasiP3 => Cow::Borrowed("wasip3"),
             WatchOS(deployment_target) => darwin_version("watchos", deployment_target),
             Windows => Cow::Borrowed("windows"),
             XROS(deployment_target) => darwin_version("xros", deployment_target),
+            Zephyr => Cow::Borrowed("zephyr"),
+            Zos => Cow::Borrowed("zos"),
         }
     }
 
     /// Whether the OS is similar to Darwin.
     ///
     /// This matches on any of:
+    /// - [bridgeOS](Self::BridgeOS)
     /// - [Darwin](Self::Darwin)
+    /// - [DriverKit](Self::DriverKit)
+    /// - [Firmware](Self::Firmware)
     /// - [iOS](Self::IOS)
     /// - [macOS](Self::MacOSX)
     /// - [tvOS](Self::TvOS)
@@ -824,26 +1520,120 @@ impl OperatingSystem {
         use OperatingSystem::*;
 
         match self {
-            Darwin(_) | IOS(_) | MacOSX(_) | TvOS(_) | VisionOS(_) | WatchOS(_) | XROS(_) => true,
+            BridgeOS(_) | Darwin(_) | DriverKit(_) | Firmware(_) | IOS(_) | MacOSX(_) | TvOS(_)
+            | VisionOS(_) | WatchOS(_) | XROS(_) => true,
             _ => false,
         }
     }
+
+    /// Return the version of the Darwin kernel that this Apple OS release
+    /// ships with, such as `darwin23` for `macosx14`.
+    ///
+    /// Only the major version is mapped, so the minor and patch versions of
+    /// the result are zero. Returns `Ok(None)` if no version is specified,
+    /// and `Err(())` if there's no known mapping for this OS or version.
+    pub fn darwin_version(&self) -> Result<Option<DeploymentTarget>, ()> {
+        use OperatingSystem::*;
+
+        let version = match *self {
+            Darwin(version) => return Ok(version),
+            DriverKit(version) | IOS(version) | MacOSX(version) | TvOS(version)
+            | VisionOS(version) | WatchOS(version) | XROS(version) => version,
+            _ => return Err(()),
+        };
+        let version = match version {
+            Some(version) => version,
+            None => return Ok(None),
+        };
+
+        let darwin = match (*self, version.major) {
+            // Mac OS X 10.x is Darwin x + 4, up until macOS 10.16, which is
+            // another name for macOS 11.
+            (MacOSX(_), 10) if version.minor <= 16 => u16::from(version.minor) + 4,
+            (MacOSX(_), major @ 11..=15) => major + 9,
+            (IOS(_), major @ 10..=18) | (TvOS(_), major @ 10..=18) => major + 6,
+            (WatchOS(_), major @ 3..=11) => major + 13,
+            (VisionOS(_), major @ 1..=2) | (XROS(_), major @ 1..=2) => major + 22,
+            (DriverKit(_), major) if major >= 19 => major,
+            // Since 2025, all the platforms are numbered after the year
+            // following their release, which is one ahead of Darwin.
+            (_, major) if major >= 26 => major - 1,
+            _ => return Err(()),
+        };
+
+        Ok(Some(DeploymentTarget {
+            major: darwin,
+            minor: 0,
+            patch: 0,
+        }))
+    }
+
+    /// Return this Apple OS at the release that ships with the given Darwin
+    /// kernel version, such as `macosx14` for `darwin23`.
+    ///
+    /// This is the inverse of [`darwin_version`](Self::darwin_version), and
+    /// likewise only maps the major version. Returns `Err(())` if there's no
+    /// known mapping for this OS or version.
+    pub fn with_darwin_version(self, darwin: DeploymentTarget) -> Result<Self, ()> {
+        use OperatingSystem::*;
+
+        let version = |major, minor| {
+            Some(DeploymentTarget {
+                major,
+                minor,
+                patch: 0,
+            })
+        };
+        // From Darwin 25, every OS is numbered after the year, one ahead of
+        // Darwin.
+        let year = |major: u16| {
+            major
+                .checked_add(1)
+                .map(|major| version(major, 0))
+                .ok_or(())
+        };
+
+        Ok(match (self, darwin.major) {
+            (Darwin(_), _) => Darwin(Some(darwin)),
+            (MacOSX(_), major @ 4..=19) => MacOSX(version(10, (major - 4) as u8)),
+            (MacOSX(_), major @ 20..=24) => MacOSX(version(major - 9, 0)),
+            (MacOSX(_), major) if major >= 25 => MacOSX(year(major)?),
+            (IOS(_), major @ 16..=24) => IOS(version(major - 6, 0)),
+            (IOS(_), major) if major >= 25 => IOS(year(major)?),
+            (TvOS(_), major @ 16..=24) => TvOS(version(major - 6, 0)),
+            (TvOS(_), major) if major >= 25 => TvOS(year(major)?),
+            (WatchOS(_), major @ 16..=24) => WatchOS(version(major - 13, 0)),
+            (WatchOS(_), major) if major >= 25 => WatchOS(year(major)?),
+            (VisionOS(_), major @ 23..=24) => VisionOS(version(major - 22, 0)),
+            (VisionOS(_), major) if major >= 25 => VisionOS(year(major)?),
+            (XROS(_), major @ 23..=24) => XROS(version(major - 22, 0)),
+            (XROS(_), major) if major >= 25 => XROS(year(major)?),
+            (DriverKit(_), major) if major >= 19 => DriverKit(version(major, 0)),
+            _ => return Err(()),
+        })
+    }
 }
 
 /// The "environment" field, which specifies an ABI environment on top of the
 /// operating system. In many configurations, this field is omitted, and the
 /// environment is implied by the operating system.
+///
+/// The Android NDK's triples may optionally include the minimum API level,
+/// as in `aarch64-linux-android21`, and the `gnu` and `musl` environments
+/// may optionally include the minimum [libc version].
+///
+/// [libc version]: LibcVersion
 #[cfg_attr(feature = "rust_1_40", non_exhaustive)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 #[allow(missing_docs)]
 pub enum Environment {
     Unknown,
     AmdGiz,
-    Android,
-    Androideabi,
+    Android(Option<u32>),
+    Androideabi(Option<u32>),
     Eabi,
     Eabihf,
-    Gnu,
+    Gnu(Option<LibcVersion>),
     Gnuabi64,
     Gnueabi,
     Gnueabihf,
@@ -855,7 +1645,9 @@ pub enum Environment {
     HurdKernel,
     LinuxKernel,
     Macabi,
-    Musl,
+    /// The managarm C library.
+    Mlibc,
+    Musl(Option<LibcVersion>),
     Musleabi,
     Musleabihf,
     Muslabi64,
@@ -879,14 +1671,30 @@ impl Environment {
     pub fn into_str(self) -> Cow<'static, str> {
         use Environment::*;
 
+        let android_api_level = |name, api_level| {
+            if let Some(api_level) = api_level {
+                Cow::Owned(format!("{}{}", name, api_level))
+            } else {
+                Cow::Borrowed(name)
+            }
+        };
+
+        let libc_version = |name, version| {
+            if let Some(version) = version {
+                Cow::Owned(format!("{}.{}", name, version))
+            } else {
+                Cow::Borrowed(name)
+            }
+        };
+
         match self {
             Unknown => Cow::Borrowed("unknown"),
             AmdGiz => Cow::Borrowed("amdgiz"),
-            Android => Cow::Borrowed("android"),
-            Androideabi => Cow::Borrowed("androideabi"),
+            Android(api_level) => android_api_level("android", api_level),
+            Androideabi(api_level) => android_api_level("androideabi", api_level),
             Eabi => Cow::Borrowed("eabi"),
             Eabihf => Cow::Borrowed("eabihf"),
-            Gnu => Cow::Borrowed("gnu"),
+            Gnu(version) => libc_version("gnu", version),
             Gnuabi64 => Cow::Borrowed("gnuabi64"),
             Gnueabi => Cow::Borrowed("gnueabi"),
             Gnueabihf => Cow::Borrowed("gnueabihf"),
@@ -898,7 +1706,8 @@ impl Environment {
             HurdKernel => Cow::Borrowed("hurdkernel"),
             LinuxKernel => Cow::Borrowed("linuxkernel"),
             Macabi => Cow::Borrowed("macabi"),
-            Musl => Cow::Borrowed("musl"),
+            Mlibc => Cow::Borrowed("mlibc"),
+            Musl(version) => libc_version("musl", version),
             Musleabi => Cow::Borrowed("musleabi"),
             Musleabihf => Cow::Borrowed("musleabihf"),
             Muslabi64 => Cow::Borrowed("muslabi64"),
@@ -917,6 +1726,24 @@ impl Environment {
             Ohos => Cow::Borrowed("ohos"),
         }
     }
+
+    /// Return the minimum Android API level, if one is specified, or
+    /// `Err(())` if this isn't an Android environment.
+    pub fn android_api_level(&self) -> Result<Option<u32>, ()> {
+        match *self {
+            Environment::Android(api_level) | Environment::Androideabi(api_level) => Ok(api_level),
+            _ => Err(()),
+        }
+    }
+
+    /// Return the minimum libc version, if one is specified, or `Err(())` if
+    /// this environment can't carry a libc version.
+    pub fn libc_version(&self) -> Result<Option<LibcVersion>, ()> {
+        match *self {
+            Environment::Gnu(version) | Environment::Musl(version) => Ok(version),
+            _ => Err(()),
+        }
+    }
 }
 
 /// The "binary format" field, which is usually omitted, and the binary format
@@ -931,6 +1758,8 @@ pub enum BinaryFormat {
     Macho,
     Wasm,
     Xcoff,
+    /// IBM's Generalized Object File Format, used on z/OS.
+    Goff,
 }
 
 impl BinaryFormat {
@@ -945,10 +1774,44 @@ impl BinaryFormat {
             Macho => Cow::Borrowed("macho"),
             Wasm => Cow::Borrowed("wasm"),
             Xcoff => Cow::Borrowed("xcoff"),
+            Goff => Cow::Borrowed("goff"),
         }
     }
 }
 
+// Mach-O CPU types and subtypes, from `<mach/machine.h>`.
+const CPU_ARCH_ABI64: u32 = 0x0100_0000;
+const CPU_ARCH_ABI64_32: u32 = 0x0200_0000;
+const CPU_TYPE_X86: u32 = 7;
+const CPU_TYPE_X86_64: u32 = CPU_TYPE_X86 | CPU_ARCH_ABI64;
+const CPU_TYPE_ARM: u32 = 12;
+const CPU_TYPE_ARM64: u32 = CPU_TYPE_ARM | CPU_ARCH_ABI64;
+const CPU_TYPE_ARM64_32: u32 = CPU_TYPE_ARM | CPU_ARCH_ABI64_32;
+const CPU_TYPE_POWERPC: u32 = 18;
+const CPU_TYPE_POWERPC64: u32 = CPU_TYPE_POWERPC | CPU_ARCH_ABI64;
+const CPU_SUBTYPE_MASK: u32 = 0xff00_0000;
+const CPU_SUBTYPE_I386_ALL: u32 = 3;
+const CPU_SUBTYPE_X86_64_ALL: u32 = 3;
+const CPU_SUBTYPE_X86_64_H: u32 = 8;
+const CPU_SUBTYPE_ARM_ALL: u32 = 0;
+const CPU_SUBTYPE_ARM_V4T: u32 = 5;
+const CPU_SUBTYPE_ARM_V6: u32 = 6;
+const CPU_SUBTYPE_ARM_V5TEJ: u32 = 7;
+const CPU_SUBTYPE_ARM_V7: u32 = 9;
+const CPU_SUBTYPE_ARM_V7S: u32 = 11;
+const CPU_SUBTYPE_ARM_V7K: u32 = 12;
+const CPU_SUBTYPE_ARM_V8: u32 = 13;
+const CPU_SUBTYPE_ARM_V6M: u32 = 14;
+const CPU_SUBTYPE_ARM_V7M: u32 = 15;
+const CPU_SUBTYPE_ARM_V7EM: u32 = 16;
+const CPU_SUBTYPE_ARM_V8M: u32 = 17;
+const CPU_SUBTYPE_ARM64_ALL: u32 = 0;
+const CPU_SUBTYPE_ARM64_V8: u32 = 1;
+const CPU_SUBTYPE_ARM64E: u32 = 2;
+const CPU_SUBTYPE_ARM64_32_ALL: u32 = 0;
+const CPU_SUBTYPE_ARM64_32_V8: u32 = 1;
+const CPU_SUBTYPE_POWERPC_ALL: u32 = 0;
+
 impl Architecture {
     /// Return the endianness of this architecture.
     #[rustfmt::skip]
@@ -957,15 +1820,26 @@ impl Architecture {
 
         match self {
             Unknown => Err(()),
+            // Like LLVM, a plain `bpf` means the endianness of the host.
+            Bpf(_) => Ok(if cfg!(target_endian = "big") {
+                Endianness::Big
+            } else {
+                Endianness::Little
+            }),
             Arm(arm) => Ok(arm.endianness()),
             Aarch64(aarch) => Ok(aarch.endianness()),
             AmdGcn
+            | Arc
             | Asmjs
             | Avr
-            | Bpfel
-            | Hexagon
+            | Bpfel(_)
+            | Hexagon(_)
+            | Kalimba
+            | Le32
+            | Le64
             | X86_32(_)
             | LoongArch64
+            | Microblazeel
             | Mips64(Mips64Architecture::Mips64el)
             | Mips32(Mips32Architecture::Mipsel)
             | Mips32(Mips32Architecture::Mipsisa32r6el)
@@ -975,16 +1849,23 @@ impl Architecture {
             | Pulley32
             | Pulley64
             | Powerpc64le
+            | R600
             | Riscv32(_)
             | Riscv64(_)
+            | Shave
+            | Sparcel
+            | Tcele
+            | Ve
             | Wasm32
             | Wasm64
             | X86_64
             | X86_64h
-            | XTensa
+            | XTensa(_)
             | Clever(_) => Ok(Endianness::Little),
-            Bpfeb
+            Bpfeb(_)
+            | Lanai
             | M68k
+            | Microblaze
             | Mips32(Mips32Architecture::Mips)
             | Mips64(Mips64Architecture::Mips64)
             | Mips32(Mips32Architecture::Mipsisa32r6)
@@ -996,7 +1877,8 @@ impl Architecture {
             | S390x
             | Sparc
             | Sparc64
-            | Sparcv9 => Ok(Endianness::Big),
+            | Sparcv9
+            | Tce => Ok(Endianness::Big),
             #[cfg(feature="arch_zkasm")]
             ZkAsm => Ok(Endianness::Big),
             #[cfg(feature = "arch_z80")]
@@ -1017,21 +1899,33 @@ impl Architecture {
             Avr | Msp430 => Ok(PointerWidth::U16),
             Arm(arm) => Ok(arm.pointer_width()),
             Aarch64(aarch) => Ok(aarch.pointer_width()),
-            Asmjs
-            | Hexagon
+            Arc
+            | Asmjs
+            | Hexagon(_)
+            | Kalimba
+            | Lanai
+            | Le32
             | X86_32(_)
             | Riscv32(_)
+            | Shave
             | Sparc
+            | Sparcel
+            | Tce
+            | Tcele
             | Wasm32
             | M68k
+            | Microblaze
+            | Microblazeel
             | Mips32(_)
             | Pulley32
             | Pulley32be
             | Powerpc
-            | XTensa => Ok(PointerWidth::U32),
+            | R600
+            | XTensa(_) => Ok(PointerWidth::U32),
             AmdGcn
-            | Bpfeb
-            | Bpfel
+            | Bpf(_)
+            | Bpfeb(_)
+            | Bpfel(_)
             | Powerpc64le
             | Riscv64(_)
             | X86_64
@@ -1045,6 +1939,8 @@ impl Architecture {
             | Sparc64
             | Sparcv9
             | LoongArch64
+            | Le64
+            | Ve
             | Wasm64
             | Clever(_) => Ok(PointerWidth::U64),
             #[cfg(feature="arch_zkasm")]
@@ -1054,6 +1950,91 @@ impl Architecture {
         }
     }
 
+    /// Return the Mach-O `cputype` and `cpusubtype` for this architecture, or
+    /// `Err(())` if it has no Mach-O equivalent.
+    pub fn to_macho_cpu(self) -> Result<(u32, u32), ()> {
+        use Architecture::*;
+
+        Ok(match self {
+            Aarch64(Aarch64Architecture::Aarch64) => (CPU_TYPE_ARM64, CPU_SUBTYPE_ARM64_ALL),
+            Aarch64(Aarch64Architecture::Arm64e) => (CPU_TYPE_ARM64, CPU_SUBTYPE_ARM64E),
+            Aarch64(Aarch64Architecture::Arm64_32) => (CPU_TYPE_ARM64_32, CPU_SUBTYPE_ARM64_32_V8),
+            Arm(arm) => {
+                let subtype = match arm {
+                    ArmArchitecture::Arm => CPU_SUBTYPE_ARM_ALL,
+                    ArmArchitecture::Armv4t | ArmArchitecture::Thumbv4t => CPU_SUBTYPE_ARM_V4T,
+                    ArmArchitecture::Armv5tej => CPU_SUBTYPE_ARM_V5TEJ,
+                    ArmArchitecture::Armv6 => CPU_SUBTYPE_ARM_V6,
+                    ArmArchitecture::Armv6m | ArmArchitecture::Thumbv6m => CPU_SUBTYPE_ARM_V6M,
+                    ArmArchitecture::Armv7
+                    | ArmArchitecture::Armv7a
+                    | ArmArchitecture::Thumbv7a => CPU_SUBTYPE_ARM_V7,
+                    ArmArchitecture::Armv7s => CPU_SUBTYPE_ARM_V7S,
+                    ArmArchitecture::Armv7k => CPU_SUBTYPE_ARM_V7K,
+                    ArmArchitecture::Armv7m | ArmArchitecture::Thumbv7m => CPU_SUBTYPE_ARM_V7M,
+                    ArmArchitecture::Thumbv7em => CPU_SUBTYPE_ARM_V7EM,
+                    ArmArchitecture::Armv8 | ArmArchitecture::Armv8a => CPU_SUBTYPE_ARM_V8,
+                    ArmArchitecture::Armv8mBase
+                    | ArmArchitecture::Armv8mMain
+                    | ArmArchitecture::Thumbv8mBase
+                    | ArmArchitecture::Thumbv8mMain => CPU_SUBTYPE_ARM_V8M,
+                    _ => return Err(()),
+                };
+                (CPU_TYPE_ARM, subtype)
+            }
+            Powerpc => (CPU_TYPE_POWERPC, CPU_SUBTYPE_POWERPC_ALL),
+            Powerpc64 => (CPU_TYPE_POWERPC64, CPU_SUBTYPE_POWERPC_ALL),
+            X86_32(_) => (CPU_TYPE_X86, CPU_SUBTYPE_I386_ALL),
+            X86_64 => (CPU_TYPE_X86_64, CPU_SUBTYPE_X86_64_ALL),
+            X86_64h => (CPU_TYPE_X86_64, CPU_SUBTYPE_X86_64_H),
+            _ => return Err(()),
+        })
+    }
+
+    /// Return the architecture for a Mach-O `cputype` and `cpusubtype`, or
+    /// `Err(())` if it isn't a known combination.
+    ///
+    /// The capability bits in the high byte of `cpusubtype`, such as the
+    /// pointer authentication ABI flag on arm64e, are ignored.
+    pub fn from_macho_cpu(cputype: u32, cpusubtype: u32) -> Result<Self, ()> {
+        use Architecture::*;
+
+        let cpusubtype = cpusubtype & !CPU_SUBTYPE_MASK;
+        Ok(match (cputype, cpusubtype) {
+            (CPU_TYPE_ARM64, CPU_SUBTYPE_ARM64_ALL) | (CPU_TYPE_ARM64, CPU_SUBTYPE_ARM64_V8) => {
+                Aarch64(Aarch64Architecture::Aarch64)
+            }
+            (CPU_TYPE_ARM64, CPU_SUBTYPE_ARM64E) => Aarch64(Aarch64Architecture::Arm64e),
+            (CPU_TYPE_ARM64_32, CPU_SUBTYPE_ARM64_32_ALL)
+            | (CPU_TYPE_ARM64_32, CPU_SUBTYPE_ARM64_32_V8) => {
+                Aarch64(Aarch64Architecture::Arm64_32)
+            }
+            (CPU_TYPE_ARM, subtype) => Arm(match subtype {
+                CPU_SUBTYPE_ARM_ALL => ArmArchitecture::Arm,
+                CPU_SUBTYPE_ARM_V4T => ArmArchitecture::Armv4t,
+                CPU_SUBTYPE_ARM_V5TEJ => ArmArchitecture::Armv5tej,
+                CPU_SUBTYPE_ARM_V6 => ArmArchitecture::Armv6,
+                CPU_SUBTYPE_ARM_V6M => ArmArchitecture::Thumbv6m,
+                CPU_SUBTYPE_ARM_V7 => ArmArchitecture::Armv7,
+                CPU_SUBTYPE_ARM_V7S => ArmArchitecture::Armv7s,
+                CPU_SUBTYPE_ARM_V7K => ArmArchitecture::Armv7k,
+                CPU_SUBTYPE_ARM_V7M => ArmArchitecture::Thumbv7m,
+                CPU_SUBTYPE_ARM_V7EM => ArmArchitecture::Thumbv7em,
+                CPU_SUBTYPE_ARM_V8 => ArmArchitecture::Armv8,
+                CPU_SUBTYPE_ARM_V8M => ArmArchitecture::Thumbv8mMain,
+                _ => return Err(()),
+            }),
+            // The PowerPC subtypes name specific processor models, which
+            // aren't distinguished here.
+            (CPU_TYPE_POWERPC, _) => Powerpc,
+            (CPU_TYPE_POWERPC64, _) => Powerpc64,
+            (CPU_TYPE_X86, CPU_SUBTYPE_I386_ALL) => X86_32(X86_32Architecture::I386),
+            (CPU_TYPE_X86_64, CPU_SUBTYPE_X86_64_ALL) => X86_64,
+            (CPU_TYPE_X86_64, CPU_SUBTYPE_X86_64_H) => X86_64h,
+            _ => return Err(()),
+        })
+    }
+
     /// Checks if this Architecture is some variant of Clever-ISA
     pub fn is_clever(&self) -> bool {
         match self {
@@ -1066,19 +2047,35 @@ impl Architecture {
     pub fn into_str(self) -> Cow<'static, str> {
         use Architecture::*;
 
+        let bpf_version = |name, version: Option<BpfVersion>| {
+            if let Some(version) = version {
+                Cow::Owned(format!("{}{}", name, version))
+            } else {
+                Cow::Borrowed(name)
+            }
+        };
+
         match self {
             Arm(arm) => arm.into_str(),
             Aarch64(aarch) => aarch.into_str(),
             Unknown => Cow::Borrowed("unknown"),
             AmdGcn => Cow::Borrowed("amdgcn"),
+            Arc => Cow::Borrowed("arc"),
             Asmjs => Cow::Borrowed("asmjs"),
             Avr => Cow::Borrowed("avr"),
-            Bpfeb => Cow::Borrowed("bpfeb"),
-            Bpfel => Cow::Borrowed("bpfel"),
-            Hexagon => Cow::Borrowed("hexagon"),
+            Bpf(version) => bpf_version("bpf", version),
+            Bpfeb(version) => bpf_version("bpfeb", version),
+            Bpfel(version) => bpf_version("bpfel", version),
+            Hexagon(hexagon) => hexagon.into_str(),
+            Kalimba => Cow::Borrowed("kalimba"),
+            Lanai => Cow::Borrowed("lanai"),
+            Le32 => Cow::Borrowed("le32"),
+            Le64 => Cow::Borrowed("le64"),
             X86_32(x86_32) => x86_32.into_str(),
             LoongArch64 => Cow::Borrowed("loongarch64"),
             M68k => Cow::Borrowed("m68k"),
+            Microblaze => Cow::Borrowed("microblaze"),
+            Microblazeel => Cow::Borrowed("microblazeel"),
             Mips32(mips32) => mips32.into_str(),
             Mips64(mips64) => mips64.into_str(),
             Msp430 => Cow::Borrowed("msp430"),
@@ -1090,17 +2087,25 @@ impl Architecture {
             Powerpc => Cow::Borrowed("powerpc"),
             Powerpc64 => Cow::Borrowed("powerpc64"),
             Powerpc64le => Cow::Borrowed("powerpc64le"),
+            R600 => Cow::Borrowed("r600"),
             Riscv32(riscv32) => riscv32.into_str(),
             Riscv64(riscv64) => riscv64.into_str(),
             S390x => Cow::Borrowed("s390x"),
+            Shave => Cow::Borrowed("shave"),
             Sparc => Cow::Borrowed("sparc"),
             Sparc64 => Cow::Borrowed("sparc64"),
+            Sparcel => Cow::Borrowed("sparcel"),
             Sparcv9 => Cow::Borrowed("sparcv9"),
+            Tce => Cow::Borrowed("tce"),
+            Tcele => Cow::Borrowed("tcele"),
+            Ve => Cow::Borrowed("ve"),
             Wasm32 => Cow::Borrowed("wasm32"),
             Wasm64 => Cow::Borrowed("wasm64"),
             X86_64 => Cow::Borrowed("x86_64"),
             X86_64h => Cow::Borrowed("x86_64h"),
-            XTensa => Cow::Borrowed("xtensa"),
+            // The core only appears in the vendor position of a triple; see
+            // `XtensaArchitecture`.
+            XTensa(_) => Cow::Borrowed("xtensa"),
             Clever(ver) => ver.into_str(),
             #[cfg(feature = "arch_zkasm")]
             ZkAsm => Cow::Borrowed("zkasm"),
@@ -1110,6 +2115,34 @@ impl Architecture {
     }
 }
 
+/// Parse a BPF architecture name, like `bpfel` or `bpfebv3`.
+///
+/// A plain `bpf` means BPF with the endianness of the host, which is kept as
+/// `Architecture::Bpf` so that it displays the way it was written.
+fn parse_bpf(s: &str) -> Result<Architecture, ()> {
+    let (endianness, rest) = if let Some(rest) = s.strip_prefix("bpfeb") {
+        (Some(Endianness::Big), rest)
+    } else if let Some(rest) = s.strip_prefix("bpfel") {
+        (Some(Endianness::Little), rest)
+    } else if let Some(rest) = s.strip_prefix("bpf") {
+        (None, rest)
+    } else {
+        return Err(());
+    };
+
+    let version = if rest.is_empty() {
+        None
+    } else {
+        Some(BpfVersion::from_str(rest)?)
+    };
+
+    Ok(match endianness {
+        Some(Endianness::Big) => Architecture::Bpfeb(version),
+        Some(Endianness::Little) => Architecture::Bpfel(version),
+        None => Architecture::Bpf(version),
+    })
+}
+
 /// Return the binary format implied by this target triple, ignoring its
 /// `binary_format` field.
 pub(crate) fn default_binary_format(triple: &Triple) -> BinaryFormat {
@@ -1119,12 +2152,16 @@ pub(crate) fn default_binary_format(triple: &Triple) -> BinaryFormat {
             _ => BinaryFormat::Unknown,
         },
         OperatingSystem::Aix => BinaryFormat::Xcoff,
+        OperatingSystem::Zos => BinaryFormat::Goff,
         os if os.is_like_darwin() => BinaryFormat::Macho,
         OperatingSystem::Windows => BinaryFormat::Coff,
         OperatingSystem::Nebulet
         | OperatingSystem::Emscripten
         | OperatingSystem::VxWorks
         | OperatingSystem::Wasi
+        | OperatingSystem::WasiP1
+        | OperatingSystem::WasiP2
+        | OperatingSystem::WasiP3
         | OperatingSystem::Unknown => match triple.architecture {
             Architecture::Wasm32 | Architecture::Wasm64 => BinaryFormat::Wasm,
             Architecture::Unknown => BinaryFormat::Unknown,
@@ -1135,31 +2172,61 @@ pub(crate) fn default_binary_format(triple: &Triple) -> BinaryFormat {
     }
 }
 
-impl fmt::Display for ArmArchitecture {
+impl fmt::Display for ArmArchitecture {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.into_str())
+    }
+}
+
+impl fmt::Display for ArmFpu {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.into_str())
+    }
+}
+
+impl fmt::Display for Aarch64Architecture {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.into_str())
+    }
+}
+
+impl fmt::Display for CleverArchitecture {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.into_str())
+    }
+}
+
+impl fmt::Display for HexagonArchitecture {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.into_str())
+    }
+}
+
+impl fmt::Display for XtensaArchitecture {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.write_str(&self.into_str())
     }
 }
 
-impl fmt::Display for Aarch64Architecture {
+impl fmt::Display for BpfVersion {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.write_str(&self.into_str())
     }
 }
 
-impl fmt::Display for CleverArchitecture {
+impl fmt::Display for Riscv32Architecture {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.write_str(&self.into_str())
     }
 }
 
-impl fmt::Display for Riscv32Architecture {
+impl fmt::Display for Riscv64Architecture {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.write_str(&self.into_str())
     }
 }
 
-impl fmt::Display for Riscv64Architecture {
+impl fmt::Display for RiscvAbi {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.write_str(&self.into_str())
     }
@@ -1250,6 +2317,36 @@ impl FromStr for ArmArchitecture {
     }
 }
 
+impl FromStr for ArmFpu {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, ()> {
+        use ArmFpu::*;
+
+        Ok(match s {
+            "vfp" => Vfp,
+            "vfpv2" => Vfpv2,
+            "vfpv3" => Vfpv3,
+            "vfpv3-fp16" => Vfpv3Fp16,
+            "vfpv3-d16" => Vfpv3D16,
+            "vfpv3xd" => Vfpv3Xd,
+            "vfpv3xd-fp16" => Vfpv3XdFp16,
+            "neon" => Neon,
+            "neon-vfpv3" => NeonVfpv3,
+            "neon-vfpv4" => NeonVfpv4,
+            "vfpv4" => Vfpv4,
+            "vfpv4-d16" => Vfpv4D16,
+            "fpv4-sp-d16" => Fpv4SpD16,
+            "fpv5-sp-d16" => Fpv5SpD16,
+            "fpv5-d16" => Fpv5D16,
+            "fp-armv8" => FpArmv8,
+            "neon-fp-armv8" => NeonFpArmv8,
+            "crypto-neon-fp-armv8" => CryptoNeonFpArmv8,
+            _ => return Err(()),
+        })
+    }
+}
+
 impl FromStr for Aarch64Architecture {
     type Err = ();
 
@@ -1260,6 +2357,9 @@ impl FromStr for Aarch64Architecture {
             "aarch64" => Aarch64,
             "arm64" => Aarch64,
             "aarch64_be" => Aarch64be,
+            "arm64e" => Arm64e,
+            "arm64_32" => Arm64_32,
+            "arm64ec" => Arm64ec,
             _ => return Err(()),
         })
     }
@@ -1276,6 +2376,64 @@ impl FromStr for CleverArchitecture {
     }
 }
 
+impl FromStr for HexagonArchitecture {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, ()> {
+        use HexagonArchitecture::*;
+
+        Ok(match s {
+            "hexagon" => Hexagon,
+            "hexagonv60" => Hexagonv60,
+            "hexagonv62" => Hexagonv62,
+            "hexagonv65" => Hexagonv65,
+            "hexagonv66" => Hexagonv66,
+            "hexagonv67" => Hexagonv67,
+            "hexagonv67t" => Hexagonv67t,
+            "hexagonv68" => Hexagonv68,
+            "hexagonv69" => Hexagonv69,
+            "hexagonv71" => Hexagonv71,
+            "hexagonv71t" => Hexagonv71t,
+            "hexagonv73" => Hexagonv73,
+            "hexagonv75" => Hexagonv75,
+            "hexagonv79" => Hexagonv79,
+            _ => return Err(()),
+        })
+    }
+}
+
+impl FromStr for XtensaArchitecture {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, ()> {
+        use XtensaArchitecture::*;
+
+        Ok(match s {
+            "xtensa" => Xtensa,
+            "esp32" => Esp32,
+            "esp32s2" => Esp32s2,
+            "esp32s3" => Esp32s3,
+            _ => return Err(()),
+        })
+    }
+}
+
+impl FromStr for BpfVersion {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, ()> {
+        use BpfVersion::*;
+
+        Ok(match s {
+            "v1" => V1,
+            "v2" => V2,
+            "v3" => V3,
+            "v4" => V4,
+            _ => return Err(()),
+        })
+    }
+}
+
 impl FromStr for Riscv32Architecture {
     type Err = ();
 
@@ -1291,6 +2449,9 @@ impl FromStr for Riscv32Architecture {
             "riscv32imac" => Riscv32imac,
             "riscv32imafc" => Riscv32imafc,
             "riscv32imc" => Riscv32imc,
+            "riscv32e" => Riscv32e,
+            "riscv32em" => Riscv32em,
+            "riscv32emc" => Riscv32emc,
             _ => return Err(()),
         })
     }
@@ -1312,6 +2473,25 @@ impl FromStr for Riscv64Architecture {
     }
 }
 
+impl FromStr for RiscvAbi {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, ()> {
+        use RiscvAbi::*;
+
+        Ok(match s {
+            "ilp32" => Ilp32,
+            "ilp32f" => Ilp32f,
+            "ilp32d" => Ilp32d,
+            "ilp32e" => Ilp32e,
+            "lp64" => Lp64,
+            "lp64f" => Lp64f,
+            "lp64d" => Lp64d,
+            _ => return Err(()),
+        })
+    }
+}
+
 impl FromStr for X86_32Architecture {
     type Err = ();
 
@@ -1391,13 +2571,17 @@ impl FromStr for Architecture {
         Ok(match s {
             "unknown" => Unknown,
             "amdgcn" => AmdGcn,
+            "arc" => Arc,
             "asmjs" => Asmjs,
             "avr" => Avr,
-            "bpfeb" => Bpfeb,
-            "bpfel" => Bpfel,
-            "hexagon" => Hexagon,
+            "kalimba" => Kalimba,
+            "lanai" => Lanai,
+            "le32" => Le32,
+            "le64" => Le64,
             "loongarch64" => LoongArch64,
             "m68k" => M68k,
+            "microblaze" => Microblaze,
+            "microblazeel" => Microblazeel,
             "msp430" => Msp430,
             "nvptx64" => Nvptx64,
             "pulley32" => Pulley32,
@@ -1407,15 +2591,22 @@ impl FromStr for Architecture {
             "powerpc" => Powerpc,
             "powerpc64" => Powerpc64,
             "powerpc64le" => Powerpc64le,
+            "r600" => R600,
             "s390x" => S390x,
+            "systemz" => S390x,
+            "shave" => Shave,
             "sparc" => Sparc,
             "sparc64" => Sparc64,
+            "sparcel" => Sparcel,
             "sparcv9" => Sparcv9,
+            "tce" => Tce,
+            "tcele" => Tcele,
+            "ve" => Ve,
             "wasm32" => Wasm32,
             "wasm64" => Wasm64,
             "x86_64" => X86_64,
             "x86_64h" => X86_64h,
-            "xtensa" => XTensa,
+            "xtensa" => XTensa(XtensaArchitecture::Xtensa),
             #[cfg(feature = "arch_zkasm")]
             "zkasm" => ZkAsm,
             _ => {
@@ -1435,6 +2626,10 @@ impl FromStr for Architecture {
                     Mips64(mips64)
                 } else if let Ok(clever) = CleverArchitecture::from_str(s) {
                     Clever(clever)
+                } else if let Ok(hexagon) = HexagonArchitecture::from_str(s) {
+                    Hexagon(hexagon)
+                } else if s.starts_with("bpf") {
+                    parse_bpf(s)?
                 } else {
                     #[cfg(feature = "arch_z80")]
                     {
@@ -1474,8 +2669,11 @@ impl FromStr for Vendor {
             "nvidia" => Nvidia,
             "pc" => Pc,
             "rumprun" => Rumprun,
+            "scei" => Scei,
+            "sie" => Sie,
             "sun" => Sun,
             "uwp" => Uwp,
+            "win7" => Win7,
             "wrs" => Wrs,
             custom => {
                 #[cfg(not(feature = "std"))]
@@ -1525,21 +2723,19 @@ impl fmt::Display for OperatingSystem {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use OperatingSystem::*;
 
-        let mut with_version = |name, deployment_target| {
-            if let Some(DeploymentTarget {
-                major,
-                minor,
-                patch,
-            }) = deployment_target
-            {
-                write!(f, "{}{}.{}.{}", name, major, minor, patch)
+        let mut with_version = |name, deployment_target: Option<DeploymentTarget>| {
+            if let Some(deployment_target) = deployment_target {
+                write!(f, "{}{}", name, deployment_target)
             } else {
                 write!(f, "{}", name)
             }
         };
 
         match *self {
+            BridgeOS(deployment_target) => with_version("bridgeos", deployment_target),
             Darwin(deployment_target) => with_version("darwin", deployment_target),
+            DriverKit(deployment_target) => with_version("driverkit", deployment_target),
+            Firmware(deployment_target) => with_version("firmware", deployment_target),
             IOS(deployment_target) => with_version("ios", deployment_target),
             MacOSX(deployment_target) => with_version("macosx", deployment_target),
             TvOS(deployment_target) => with_version("tvos", deployment_target),
@@ -1557,68 +2753,73 @@ impl FromStr for OperatingSystem {
     fn from_str(s: &str) -> Result<Self, ()> {
         use OperatingSystem::*;
 
-        let parse_darwin = |name: &str| {
+        let parse_version = |name: &str| {
             let s = &s[name.len()..];
-            let mut parts = s.split('.');
 
             if s.is_empty() {
                 // Not specifying a version is allowed!
                 return Ok(None);
             }
 
-            let major = if let Some(part) = parts.next() {
-                part.parse().map_err(|_| ())?
-            } else {
-                // If the string was just `.`, with no major version, that's
-                // clearly an error.
-                return Err(());
-            };
-            let minor = if let Some(part) = parts.next() {
-                part.parse().map_err(|_| ())?
-            } else {
-                // Fall back to 0 if no minor version was set
-                0
-            };
-            let patch = if let Some(part) = parts.next() {
-                part.parse().map_err(|_| ())?
-            } else {
-                // Fall back to 0 if no patch version was set
-                0
-            };
+            s.parse().map(Some)
+        };
+        let parse_release = |name: &str| {
+            let s = &s[name.len()..];
 
-            if parts.next().is_some() {
-                // Too many parts
-                return Err(());
+            if s.is_empty() {
+                return Ok(None);
             }
 
-            Ok(Some(DeploymentTarget {
-                major,
-                minor,
-                patch,
-            }))
+            s.parse().map(Some)
         };
 
         // Parse operating system names that contain a version, like `macosx10.7.0`.
+        if s.starts_with("bridgeos") {
+            return Ok(BridgeOS(parse_version("bridgeos")?));
+        }
         if s.starts_with("darwin") {
-            return Ok(Darwin(parse_darwin("darwin")?));
+            return Ok(Darwin(parse_version("darwin")?));
+        }
+        if s.starts_with("driverkit") {
+            return Ok(DriverKit(parse_version("driverkit")?));
+        }
+        if s.starts_with("firmware") {
+            return Ok(Firmware(parse_version("firmware")?));
         }
         if s.starts_with("ios") {
-            return Ok(IOS(parse_darwin("ios")?));
+            return Ok(IOS(parse_version("ios")?));
         }
         if s.starts_with("macosx") {
-            return Ok(MacOSX(parse_darwin("macosx")?));
+            return Ok(MacOSX(parse_version("macosx")?));
         }
         if s.starts_with("tvos") {
-            return Ok(TvOS(parse_darwin("tvos")?));
+            return Ok(TvOS(parse_version("tvos")?));
         }
         if s.starts_with("visionos") {
-            return Ok(VisionOS(parse_darwin("visionos")?));
+            return Ok(VisionOS(parse_version("visionos")?));
         }
         if s.starts_with("watchos") {
-            return Ok(WatchOS(parse_darwin("watchos")?));
+            return Ok(WatchOS(parse_version("watchos")?));
         }
         if s.starts_with("xros") {
-            return Ok(XROS(parse_darwin("xros")?));
+            return Ok(XROS(parse_version("xros")?));
+        }
+
+        // Likewise for BSD and Solaris releases, like `freebsd13.2`.
+        if s.starts_with("dragonfly") {
+            return Ok(Dragonfly(parse_release("dragonfly")?));
+        }
+        if s.starts_with("freebsd") {
+            return Ok(Freebsd(parse_release("freebsd")?));
+        }
+        if s.starts_with("netbsd") {
+            return Ok(Netbsd(parse_release("netbsd")?));
+        }
+        if s.starts_with("openbsd") {
+            return Ok(Openbsd(parse_release("openbsd")?));
+        }
+        if s.starts_with("solaris") {
+            return Ok(Solaris(parse_release("solaris")?));
         }
 
         Ok(match s {
@@ -1629,9 +2830,8 @@ impl FromStr for OperatingSystem {
             "cloudabi" => Cloudabi,
             "cuda" => Cuda,
             "cygwin" => Cygwin,
-            "dragonfly" => Dragonfly,
             "emscripten" => Emscripten,
-            "freebsd" => Freebsd,
+            "freertos" => Freertos,
             "fuchsia" => Fuchsia,
             "haiku" => Haiku,
             "hermit" => Hermit,
@@ -1640,21 +2840,30 @@ impl FromStr for OperatingSystem {
             "illumos" => Illumos,
             "l4re" => L4re,
             "linux" => Linux,
+            "liteos" => Liteos,
+            "lv2" => Lv2,
+            "managarm" => Managarm,
             "nebulet" => Nebulet,
-            "netbsd" => Netbsd,
             "none" => None_,
-            "openbsd" => Openbsd,
+            "nuttx" => Nuttx,
+            "ps4" => Ps4,
+            "ps5" => Ps5,
             "psp" => Psp,
             "redox" => Redox,
-            "solaris" => Solaris,
+            "rtems" => Rtems,
+            "serenity" => Serenity,
             "solid_asp3" => SolidAsp3,
+            "theseus" => Theseus,
             "uefi" => Uefi,
             "vxworks" => VxWorks,
             "wasi" => Wasi,
             "wasip1" => WasiP1,
             "wasip2" => WasiP2,
+            "wasip3" => WasiP3,
             "windows" => Windows,
             "espidf" => Espidf,
+            "zephyr" => Zephyr,
+            "zos" => Zos,
             _ => return Err(()),
         })
     }
@@ -1672,14 +2881,39 @@ impl FromStr for Environment {
     fn from_str(s: &str) -> Result<Self, ()> {
         use Environment::*;
 
+        let parse_android = |name: &str| {
+            let s = &s[name.len()..];
+
+            if s.is_empty() {
+                // Not specifying an API level is allowed!
+                return Ok(Option::None);
+            }
+
+            parse_version_part(s).map(Some)
+        };
+
+        // Parse environment names that contain an API level, like `android21`.
+        if s.starts_with("androideabi") {
+            return Ok(Androideabi(parse_android("androideabi")?));
+        }
+        if s.starts_with("android") {
+            return Ok(Android(parse_android("android")?));
+        }
+
+        // Parse environment names that contain a libc version, like `gnu.2.17`.
+        if let Some(version) = s.strip_prefix("gnu.") {
+            return Ok(Gnu(Some(version.parse()?)));
+        }
+        if let Some(version) = s.strip_prefix("musl.") {
+            return Ok(Musl(Some(version.parse()?)));
+        }
+
         Ok(match s {
             "unknown" => Unknown,
             "amdgiz" => AmdGiz,
-            "android" => Android,
-            "androideabi" => Androideabi,
             "eabi" => Eabi,
             "eabihf" => Eabihf,
-            "gnu" => Gnu,
+            "gnu" => Gnu(Option::None),
             "gnuabi64" => Gnuabi64,
             "gnueabi" => Gnueabi,
             "gnueabihf" => Gnueabihf,
@@ -1691,7 +2925,8 @@ impl FromStr for Environment {
             "hurdkernel" => HurdKernel,
             "linuxkernel" => LinuxKernel,
             "macabi" => Macabi,
-            "musl" => Musl,
+            "mlibc" => Mlibc,
+            "musl" => Musl(Option::None),
             "musleabi" => Musleabi,
             "musleabihf" => Musleabihf,
             "muslabi64" => Muslabi64,
@@ -1704,6 +2939,8 @@ impl FromStr for Environment {
             "uclibceabihf" => Uclibceabihf,
             "sgx" => Sgx,
             "sim" => Sim,
+            // Swift and clang also accept the spelled-out name.
+            "simulator" => Sim,
             "softfloat" => Softfloat,
             "spe" => Spe,
             "threads" => Threads,
@@ -1732,6 +2969,7 @@ impl FromStr for BinaryFormat {
             "macho" => Macho,
             "wasm" => Wasm,
             "xcoff" => Xcoff,
+            "goff" => Goff,
             _ => return Err(()),
         })
     }
@@ -1749,7 +2987,11 @@ mod tests {
         //  - targets emitted by "rustc +nightly --print target-list"
         //  - targets contributors have added
         let targets = [
+            "aarch64-apple-bridgeos",
             "aarch64-apple-darwin",
+            "aarch64-apple-driverkit",
+            "aarch64-apple-driverkit21.0.0",
+            "aarch64-apple-firmware",
             "aarch64-apple-ios",
             "aarch64-apple-ios-macabi",
             "aarch64-apple-ios-sim",
@@ -1764,6 +3006,7 @@ mod tests {
             "aarch64_be-unknown-netbsd",
             "aarch64-kmc-solid_asp3",
             "aarch64-linux-android",
+            "aarch64-linux-android21",
             //"aarch64-nintendo-switch-freestanding", // TODO
             "aarch64-pc-windows-gnullvm",
             "aarch64-pc-windows-msvc",
@@ -1775,23 +3018,27 @@ mod tests {
             "aarch64-unknown-linux-gnu",
             "aarch64-unknown-linux-gnu_ilp32",
             "aarch64-unknown-linux-musl",
+            "aarch64-unknown-linux-musl.1.2.3",
             "aarch64-unknown-linux-ohos",
             "aarch64-unknown-netbsd",
             "aarch64-unknown-none",
             "aarch64-unknown-none-softfloat",
+            "aarch64-unknown-nuttx",
             //"aarch64-unknown-nto-qnx710", // TODO
             "aarch64-unknown-openbsd",
+            "aarch64-unknown-managarm-mlibc",
             "aarch64-unknown-redox",
+            "aarch64-unknown-serenity",
             //"aarch64-unknown-teeos", // TODO
             "aarch64-unknown-uefi",
             "aarch64-uwp-windows-msvc",
             "aarch64-wrs-vxworks",
-            //"arm64_32-apple-watchos", // TODO
-            //"arm64e-apple-darwin", // TODO
+            "arm64_32-apple-watchos",
+            "arm64e-apple-darwin",
             "amdgcn-amd-amdhsa",
             "amdgcn-amd-amdhsa-amdgiz",
-            //"arm64e-apple-ios", // TODO
-            //"arm64ec-pc-windows-msvc", // TODO
+            "arm64e-apple-ios",
+            "arm64ec-pc-windows-msvc",
             "armeb-unknown-linux-gnueabi",
             "armebv7r-none-eabi",
             "armebv7r-none-eabihf",
@@ -1813,9 +3060,13 @@ mod tests {
             "armv7a-kmc-solid_asp3-eabihf",
             "armv7a-none-eabi",
             "armv7a-none-eabihf",
+            "armv7a-nuttx-eabi",
+            "armv7a-nuttx-eabihf",
             "armv7-apple-ios",
             "armv7k-apple-watchos",
             "armv7-linux-androideabi",
+            "armv7a-linux-androideabi24",
+            "armv7-rtems-eabihf",
             "armv7r-none-eabi",
             "armv7r-none-eabihf",
             "armv7s-apple-ios",
@@ -1827,6 +3078,7 @@ mod tests {
             "armv7-unknown-linux-musleabi",
             "armv7-unknown-linux-musleabihf",
             "armv7-unknown-linux-ohos",
+            "arm-unknown-liteos",
             "armv7-unknown-linux-uclibceabi",
             "armv7-unknown-linux-uclibceabihf",
             "armv7-unknown-netbsd-eabihf",
@@ -1835,6 +3087,7 @@ mod tests {
             "armv8r-none-eabihf",
             //"avr-unknown-gnu-atmega328", // TODO
             "avr-unknown-unknown",
+            "bpf-unknown-none",
             "bpfeb-unknown-none",
             "bpfel-unknown-none",
             //"csky-unknown-linux-gnuabiv2", // TODO
@@ -1895,6 +3148,7 @@ mod tests {
             "msp430-none-elf",
             "nvptx64-nvidia-cuda",
             "powerpc64-ibm-aix",
+            "powerpc64-scei-lv2",
             "powerpc64le-unknown-freebsd",
             "powerpc64le-unknown-linux-gnu",
             "powerpc64le-unknown-linux-musl",
@@ -1916,12 +3170,18 @@ mod tests {
             "riscv32gc-unknown-linux-musl",
             "riscv32imac-esp-espidf",
             "riscv32imac-unknown-none-elf",
+            "riscv32imac-unknown-nuttx-elf",
             //"riscv32imac-unknown-xous-elf", // TODO
             "riscv32imafc-esp-espidf",
             "riscv32imafc-unknown-none-elf",
+            "riscv32imafc-unknown-nuttx-elf",
             "riscv32ima-unknown-none-elf",
             "riscv32imc-esp-espidf",
             "riscv32imc-unknown-none-elf",
+            "riscv32imc-unknown-nuttx-elf",
+            "riscv32e-unknown-none-elf",
+            "riscv32em-unknown-none-elf",
+            "riscv32emc-unknown-none-elf",
             //"riscv32im-risc0-zkvm-elf", // TODO
             "riscv32im-unknown-none-elf",
             "riscv32i-unknown-none-elf",
@@ -1933,31 +3193,50 @@ mod tests {
             "riscv64gc-unknown-linux-musl",
             "riscv64gc-unknown-netbsd",
             "riscv64gc-unknown-none-elf",
+            "riscv64gc-unknown-nuttx-elf",
+            "riscv64gc-unknown-managarm-mlibc",
             "riscv64gc-unknown-openbsd",
+            "riscv64gc-unknown-redox",
             "riscv64imac-unknown-none-elf",
+            "riscv64imac-unknown-nuttx-elf",
             "riscv64-linux-android",
             "s390x-unknown-linux-gnu",
             "s390x-unknown-linux-musl",
+            "s390x-ibm-zos",
+            "s390x-ibm-zos-elf",
             "sparc64-unknown-linux-gnu",
             "sparc64-unknown-netbsd",
+            "sparc64-unknown-netbsd9",
             "sparc64-unknown-openbsd",
             "sparc-unknown-linux-gnu",
             "sparc-unknown-none-elf",
+            "sparc-unknown-rtems",
             "sparcv9-sun-solaris",
+            "sparcv9-sun-solaris2.11",
             "thumbv4t-none-eabi",
             "thumbv5te-none-eabi",
             "thumbv6m-none-eabi",
+            "thumbv6m-nuttx-eabi",
+            "thumbv7a-nuttx-eabi",
+            "thumbv7a-nuttx-eabihf",
             "thumbv7a-pc-windows-msvc",
             "thumbv7a-uwp-windows-msvc",
             "thumbv7em-none-eabi",
             "thumbv7em-none-eabihf",
+            "thumbv7em-nuttx-eabi",
+            "thumbv7em-nuttx-eabihf",
+            "thumbv7em-unknown-zephyr-eabihf",
             "thumbv7m-none-eabi",
+            "thumbv7m-nuttx-eabi",
             "thumbv7neon-linux-androideabi",
             "thumbv7neon-unknown-linux-gnueabihf",
             "thumbv7neon-unknown-linux-musleabihf",
             "thumbv8m.base-none-eabi",
             "thumbv8m.main-none-eabi",
             "thumbv8m.main-none-eabihf",
+            "thumbv8m.base-nuttx-eabi",
+            "thumbv8m.main-nuttx-eabi",
+            "thumbv8m.main-nuttx-eabihf",
             "wasm32-experimental-emscripten",
             "wasm32-unknown-emscripten",
             "wasm32-unknown-unknown",
@@ -1965,10 +3244,12 @@ mod tests {
             "wasm32-wasip1",
             "wasm32-wasip1-threads",
             "wasm32-wasip2",
+            "wasm32-wasip3",
             "wasm64-unknown-unknown",
             "wasm64-wasi",
             "x86_64-apple-darwin",
             "x86_64-apple-darwin23.6.0",
+            "x86_64-apple-driverkit19.0.0",
             "x86_64-apple-ios",
             "x86_64-apple-ios-macabi",
             "x86_64-apple-tvos",
@@ -1976,22 +3257,29 @@ mod tests {
             "x86_64-fortanix-unknown-sgx",
             "x86_64h-apple-darwin",
             "x86_64-linux-android",
+            "x86_64-linux-android34",
             //"x86_64-pc-nto-qnx710", // TODO
             "x86_64-linux-kernel", // Changed to x86_64-unknown-none-linuxkernel in 1.53.0
             "x86_64-apple-macosx",
             "x86_64-apple-macosx10.7.0",
             "x86_64-pc-cygwin",
+            "x86_64-pc-serenity",
             "x86_64-pc-solaris",
             "x86_64-pc-windows-gnu",
             "x86_64-pc-windows-gnullvm",
             "x86_64-pc-windows-msvc",
             "x86_64-rumprun-netbsd", // Removed in 1.53.0
+            "x86_64-scei-ps4",
+            "x86_64-sie-ps5",
             "x86_64-sun-solaris",
             "x86_64-unknown-bitrig",
             "x86_64-unknown-cloudabi",
             "x86_64-unikraft-linux-musl",
             "x86_64-unknown-dragonfly",
+            "x86_64-unknown-dragonfly6.4",
             "x86_64-unknown-freebsd",
+            "x86_64-unknown-freebsd13.2",
+            "x86_64-unknown-freertos",
             "x86_64-unknown-fuchsia",
             "x86_64-unknown-haiku",
             "x86_64-unknown-hermit-kernel", // Changed to x86_64-unknown-none-hermitkernel in 1.53.0
@@ -1999,6 +3287,7 @@ mod tests {
             "x86_64-unknown-illumos",
             "x86_64-unknown-l4re-uclibc",
             "x86_64-unknown-linux-gnu",
+            "x86_64-unknown-linux-gnu.2.17",
             "x86_64-unknown-linux-gnux32",
             "x86_64-unknown-linux-musl",
             "x86_64-unknown-linux-none",
@@ -2008,10 +3297,15 @@ mod tests {
             "x86_64-unknown-none-hermitkernel",
             "x86_64-unknown-none-linuxkernel",
             "x86_64-unknown-openbsd",
+            "x86_64-unknown-openbsd7.5",
+            "x86_64-unknown-managarm-mlibc",
             "x86_64-unknown-redox",
+            "x86_64-unknown-serenity",
+            "x86_64-unknown-theseus",
             "x86_64-unknown-uefi",
             "x86_64-uwp-windows-gnu",
             "x86_64-uwp-windows-msvc",
+            "x86_64-win7-windows-gnu",
             "x86_64-win7-windows-msvc",
             "x86_64-wrs-vxworks",
             "xtensa-esp32-espidf",
@@ -2038,6 +3332,527 @@ mod tests {
         }
     }
 
+    #[test]
+    fn roundtrip_arm_fpus() {
+        for fpu in &[
+            "vfp",
+            "vfpv2",
+            "vfpv3",
+            "vfpv3-fp16",
+            "vfpv3-d16",
+            "vfpv3xd",
+            "vfpv3xd-fp16",
+            "neon",
+            "neon-vfpv3",
+            "neon-vfpv4",
+            "vfpv4",
+            "vfpv4-d16",
+            "fpv4-sp-d16",
+            "fpv5-sp-d16",
+            "fpv5-d16",
+            "fp-armv8",
+            "neon-fp-armv8",
+            "crypto-neon-fp-armv8",
+        ] {
+            assert_eq!(ArmFpu::from_str(fpu).unwrap().to_string(), *fpu);
+        }
+    }
+
+    #[test]
+    fn hexagon_versions() {
+        let t = Triple::from_str("hexagonv68-unknown-linux-musl").expect("can't parse target");
+        assert_eq!(
+            t.architecture,
+            Architecture::Hexagon(HexagonArchitecture::Hexagonv68)
+        );
+        assert_eq!(t.to_string(), "hexagonv68-unknown-linux-musl");
+        assert_eq!(t.pointer_width(), Ok(PointerWidth::U32));
+
+        let t = Triple::from_str("hexagon-unknown-none-elf").expect("can't parse target");
+        assert_eq!(
+            t.architecture,
+            Architecture::Hexagon(HexagonArchitecture::Hexagon)
+        );
+        assert!(!HexagonArchitecture::Hexagon.has_hvx());
+
+        assert_eq!(
+            HexagonArchitecture::from_cpu("hexagonv73"),
+            Ok(HexagonArchitecture::Hexagonv73)
+        );
+        assert_eq!(
+            HexagonArchitecture::from_cpu("v66"),
+            Ok(HexagonArchitecture::Hexagonv66)
+        );
+        assert!(HexagonArchitecture::from_cpu("v1").is_err());
+
+        assert_eq!(
+            HexagonArchitecture::Hexagonv68.hvx_vector_lengths(),
+            &[64, 128]
+        );
+        assert_eq!(HexagonArchitecture::Hexagonv75.hvx_vector_lengths(), &[128]);
+        assert!(!HexagonArchitecture::Hexagonv71t.has_hvx());
+        assert_eq!(HexagonArchitecture::Hexagonv71t.version(), Some(71));
+    }
+
+    #[test]
+    fn xtensa_cores() {
+        let t = Triple::from_str("xtensa-esp32s3-none-elf").expect("can't parse target");
+        assert_eq!(
+            t.architecture,
+            Architecture::XTensa(XtensaArchitecture::Esp32s3)
+        );
+        assert_eq!(t.vendor, Vendor::Espressif);
+        assert_eq!(t.operating_system, OperatingSystem::None_);
+        assert_eq!(t.binary_format, BinaryFormat::Elf);
+
+        let t = Triple::from_str("xtensa-esp32s2-espidf").expect("can't parse target");
+        assert_eq!(
+            t.architecture,
+            Architecture::XTensa(XtensaArchitecture::Esp32s2)
+        );
+        assert_eq!(t.vendor, Vendor::Espressif);
+        assert_eq!(t.operating_system, OperatingSystem::Espidf);
+
+        let t = Triple::from_str("xtensa-unknown-none-elf").expect("can't parse target");
+        assert_eq!(
+            t.architecture,
+            Architecture::XTensa(XtensaArchitecture::Xtensa)
+        );
+        assert_eq!(t.vendor, Vendor::Unknown);
+
+        assert!(XtensaArchitecture::Esp32.has_fpu());
+        assert!(!XtensaArchitecture::Esp32s2.has_fpu());
+        assert!(XtensaArchitecture::Esp32s2.has_windowed_registers());
+        assert!(Architecture::from_str("esp32").is_err());
+
+        // Without the Espressif vendor there's nowhere to print the core.
+        assert_eq!(
+            Architecture::XTensa(XtensaArchitecture::Esp32).to_string(),
+            "xtensa"
+        );
+        let t = Triple {
+            architecture: Architecture::XTensa(XtensaArchitecture::Esp32),
+            vendor: Vendor::Unknown,
+            operating_system: OperatingSystem::None_,
+            environment: Environment::Unknown,
+            binary_format: BinaryFormat::Elf,
+        };
+        assert_eq!(t.to_string(), "xtensa-unknown-none-elf");
+        assert_eq!(
+            Triple::from_str(&t.to_string()).unwrap().architecture,
+            Architecture::XTensa(XtensaArchitecture::Xtensa)
+        );
+        let t = Triple {
+            vendor: Vendor::Espressif,
+            ..t
+        };
+        assert_eq!(t.to_string(), "xtensa-esp32-none-elf");
+    }
+
+    #[test]
+    fn android_api_levels() {
+        let t = Triple::from_str("aarch64-linux-android21").expect("can't parse target");
+        assert_eq!(t.environment, Environment::Android(Some(21)));
+        assert_eq!(t.environment.android_api_level(), Ok(Some(21)));
+        assert_eq!(t.vendor, Vendor::Unknown);
+        assert_eq!(t.to_string(), "aarch64-linux-android21");
+
+        let t = Triple::from_str("armv7a-linux-androideabi24").expect("can't parse target");
+        assert_eq!(t.environment, Environment::Androideabi(Some(24)));
+        assert_eq!(t.to_string(), "armv7a-linux-androideabi24");
+
+        let t = Triple::from_str("x86_64-linux-android").expect("can't parse target");
+        assert_eq!(t.environment, Environment::Android(None));
+        assert_eq!(t.environment.android_api_level(), Ok(None));
+        assert_eq!(Environment::Msvc.android_api_level(), Err(()));
+
+        assert!(Environment::from_str("android+21").is_err());
+        assert!(Environment::from_str("android21.0").is_err());
+        assert!(Environment::from_str("androideabix").is_err());
+    }
+
+    #[test]
+    fn libc_versions() {
+        let t = Triple::from_str("x86_64-unknown-linux-gnu.2.17").expect("can't parse target");
+        let glibc_2_17 = LibcVersion {
+            major: 2,
+            minor: 17,
+            patch: 0,
+        };
+        assert_eq!(t.environment, Environment::Gnu(Some(glibc_2_17)));
+        assert_eq!(t.environment.libc_version(), Ok(Some(glibc_2_17)));
+        assert_eq!(t.to_string(), "x86_64-unknown-linux-gnu.2.17");
+
+        let t = Triple::from_str("x86_64-unknown-linux-gnu").expect("can't parse target");
+        assert_eq!(t.environment.libc_version(), Ok(None));
+        assert_eq!(Environment::Msvc.libc_version(), Err(()));
+
+        let musl = Environment::from_str("musl.1.2.3").unwrap();
+        assert_eq!(musl.to_string(), "musl.1.2.3");
+        assert_eq!(
+            musl.libc_version(),
+            Ok(Some(LibcVersion {
+                major: 1,
+                minor: 2,
+                patch: 3,
+            }))
+        );
+
+        assert!(LibcVersion::from_str("2.28").unwrap() > glibc_2_17);
+        assert!(LibcVersion::from_str("2.17.1").unwrap() > glibc_2_17);
+        assert!(LibcVersion::from_str("2.9").unwrap() < glibc_2_17);
+        assert_eq!(LibcVersion::from_str("2.17.0"), Ok(glibc_2_17));
+        assert_eq!(glibc_2_17.to_string(), "2.17");
+
+        assert!(Environment::from_str("gnu.").is_err());
+        assert!(Environment::from_str("gnu.2").is_err());
+        assert!(Environment::from_str("gnu.2.17.1.1").is_err());
+        assert!(Environment::from_str("musl.x.y").is_err());
+        assert!(Environment::from_str("gnu.+2.17").is_err());
+        assert!(Environment::from_str("gnu.2.+17").is_err());
+        assert!(LibcVersion::from_str("2.17.+1").is_err());
+        assert!(Environment::from_str("gnueabihf.2.17").is_err());
+    }
+
+    #[test]
+    fn bsd_releases() {
+        let release = |major, minor, patch, components| {
+            Some(OsRelease {
+                version: DeploymentTarget::new(major, minor, patch),
+                components,
+            })
+        };
+
+        let t = Triple::from_str("x86_64-unknown-freebsd13.2").expect("can't parse target");
+        assert_eq!(
+            t.operating_system,
+            OperatingSystem::Freebsd(release(13, 2, 0, 2))
+        );
+
+        let t = Triple::from_str("sparc64-unknown-netbsd9.0.0").expect("can't parse target");
+        assert_eq!(
+            t.operating_system,
+            OperatingSystem::Netbsd(release(9, 0, 0, 3))
+        );
+
+        // Releases are displayed the way they were written.
+        for triple in &[
+            "x86_64-unknown-freebsd13.2",
+            "x86_64-unknown-freebsd14.0",
+            "x86_64-unknown-freebsd14",
+            "sparc64-unknown-netbsd9.0.0",
+            "x86_64-unknown-dragonfly6.4.1",
+            "sparcv9-sun-solaris2.11",
+            "x86_64-unknown-openbsd7.5",
+        ] {
+            let t = Triple::from_str(triple).expect("can't parse target");
+            assert_eq!(t.to_string(), *triple);
+        }
+
+        assert_eq!(
+            OperatingSystem::from_str("solaris2.11"),
+            Ok(OperatingSystem::Solaris(release(2, 11, 0, 2)))
+        );
+        assert_eq!(
+            OperatingSystem::from_str("openbsd"),
+            Ok(OperatingSystem::Openbsd(None))
+        );
+        assert_eq!(
+            OperatingSystem::Dragonfly(release(6, 4, 1, 1)).into_str(),
+            "dragonfly6.4.1"
+        );
+        assert!(OperatingSystem::from_str("freebsd13.2.1.1").is_err());
+        assert!(OperatingSystem::from_str("freebsdx").is_err());
+    }
+
+    #[test]
+    fn darwin_versions() {
+        let version = |major, minor| {
+            Some(DeploymentTarget {
+                major,
+                minor,
+                patch: 0,
+            })
+        };
+
+        for (os, darwin) in &[
+            (OperatingSystem::MacOSX(version(10, 4)), 8),
+            (OperatingSystem::MacOSX(version(10, 15)), 19),
+            (OperatingSystem::MacOSX(version(10, 16)), 20),
+            (OperatingSystem::MacOSX(version(11, 0)), 20),
+            (OperatingSystem::MacOSX(version(14, 0)), 23),
+            (OperatingSystem::MacOSX(version(15, 2)), 24),
+            (OperatingSystem::MacOSX(version(26, 0)), 25),
+            (OperatingSystem::IOS(version(17, 0)), 23),
+            (OperatingSystem::IOS(version(26, 1)), 25),
+            (OperatingSystem::TvOS(version(10, 0)), 16),
+            (OperatingSystem::WatchOS(version(10, 0)), 23),
+            (OperatingSystem::VisionOS(version(1, 0)), 23),
+            (OperatingSystem::XROS(version(2, 0)), 24),
+            (OperatingSystem::DriverKit(version(19, 0)), 19),
+        ] {
+            assert_eq!(os.darwin_version(), Ok(version(*darwin, 0)), "{}", os);
+        }
+
+        assert_eq!(OperatingSystem::MacOSX(None).darwin_version(), Ok(None));
+        assert_eq!(
+            OperatingSystem::Darwin(version(23, 1)).darwin_version(),
+            Ok(version(23, 1))
+        );
+        assert!(OperatingSystem::MacOSX(version(9, 0))
+            .darwin_version()
+            .is_err());
+        assert!(OperatingSystem::MacOSX(version(20, 0))
+            .darwin_version()
+            .is_err());
+        assert!(OperatingSystem::Linux.darwin_version().is_err());
+
+        let darwin = |major| DeploymentTarget {
+            major,
+            minor: 0,
+            patch: 0,
+        };
+        assert_eq!(
+            OperatingSystem::MacOSX(None).with_darwin_version(darwin(23)),
+            Ok(OperatingSystem::MacOSX(version(14, 0)))
+        );
+        assert_eq!(
+            OperatingSystem::MacOSX(None).with_darwin_version(darwin(13)),
+            Ok(OperatingSystem::MacOSX(version(10, 9)))
+        );
+        assert_eq!(
+            OperatingSystem::IOS(None).with_darwin_version(darwin(23)),
+            Ok(OperatingSystem::IOS(version(17, 0)))
+        );
+        assert_eq!(
+            OperatingSystem::WatchOS(None).with_darwin_version(darwin(25)),
+            Ok(OperatingSystem::WatchOS(version(26, 0)))
+        );
+        assert!(OperatingSystem::IOS(None)
+            .with_darwin_version(darwin(12))
+            .is_err());
+        assert!(OperatingSystem::Linux
+            .with_darwin_version(darwin(23))
+            .is_err());
+        assert!(OperatingSystem::Freebsd(None)
+            .with_darwin_version(darwin(23))
+            .is_err());
+        assert_eq!(
+            OperatingSystem::DriverKit(None).with_darwin_version(darwin(23)),
+            Ok(OperatingSystem::DriverKit(version(23, 0)))
+        );
+    }
+
+    #[test]
+    fn deployment_target_versions() {
+        let v10_12 = DeploymentTarget::new(10, 12, 0);
+        assert_eq!(DeploymentTarget::from_str("10.12"), Ok(v10_12));
+        assert_eq!(DeploymentTarget::from_str("10.12.0"), Ok(v10_12));
+        assert_eq!(
+            DeploymentTarget::from_str("14"),
+            Ok(DeploymentTarget::new(14, 0, 0))
+        );
+        assert_eq!(v10_12.to_string(), "10.12.0");
+        assert!(DeploymentTarget::from_str("").is_err());
+        assert!(DeploymentTarget::from_str("10.256").is_err());
+        assert!(DeploymentTarget::from_str("10.12.0.1").is_err());
+        assert!(DeploymentTarget::from_str("10.x").is_err());
+        assert!(DeploymentTarget::from_str("+10").is_err());
+        assert!(DeploymentTarget::from_str("10.+12").is_err());
+        assert!(DeploymentTarget::from_str("10.12.+1").is_err());
+        assert!(Triple::from_str("x86_64-unknown-freebsd+13").is_err());
+        assert!(Triple::from_str("x86_64-apple-macosx+10.12").is_err());
+
+        assert_eq!(
+            DeploymentTarget::checked_new(65535, 255, 255),
+            Ok(DeploymentTarget::new(65535, 255, 255))
+        );
+        assert!(DeploymentTarget::checked_new(65536, 0, 0).is_err());
+        assert!(DeploymentTarget::checked_new(10, 256, 0).is_err());
+        assert!(DeploymentTarget::checked_new(10, 0, 256).is_err());
+
+        let v13_4_1 = DeploymentTarget::new(13, 4, 1);
+        assert_eq!(v13_4_1.to_macho_u32(), 0x000d_0401);
+        assert_eq!(DeploymentTarget::from_macho_u32(0x000d_0401), v13_4_1);
+        assert_eq!(DeploymentTarget::from_macho_u32(0x000a_0c00), v10_12);
+
+        let supported = DeploymentTarget::new(10, 12, 0)..=DeploymentTarget::new(14, 0, 0);
+        assert!(supported.contains(&v13_4_1));
+        assert!(supported.contains(&v10_12));
+        assert!(!supported.contains(&DeploymentTarget::new(10, 11, 6)));
+        assert!(!supported.contains(&DeploymentTarget::new(14, 0, 1)));
+    }
+
+    #[test]
+    fn macho_cpus() {
+        for (arch, cputype, cpusubtype) in &[
+            ("x86_64", 0x0100_0007, 3),
+            ("x86_64h", 0x0100_0007, 8),
+            ("i386", 7, 3),
+            ("aarch64", 0x0100_000c, 0),
+            ("arm64e", 0x0100_000c, 2),
+            ("arm64_32", 0x0200_000c, 1),
+            ("armv7", 12, 9),
+            ("armv7s", 12, 11),
+            ("armv7k", 12, 12),
+            ("thumbv7em", 12, 16),
+            ("powerpc", 18, 0),
+            ("powerpc64", 0x0100_0012, 0),
+        ] {
+            let arch = Architecture::from_str(arch).unwrap();
+            assert_eq!(arch.to_macho_cpu(), Ok((*cputype, *cpusubtype)), "{}", arch);
+            assert_eq!(
+                Architecture::from_macho_cpu(*cputype, *cpusubtype),
+                Ok(arch),
+                "{}",
+                arch
+            );
+        }
+
+        // arm64e binaries set the pointer authentication ABI flag.
+        assert_eq!(
+            Architecture::from_macho_cpu(0x0100_000c, 0x8000_0002),
+            Ok(Architecture::Aarch64(Aarch64Architecture::Arm64e))
+        );
+        assert_eq!(
+            Architecture::from_macho_cpu(0x0100_000c, 1),
+            Ok(Architecture::Aarch64(Aarch64Architecture::Aarch64))
+        );
+        assert_eq!(
+            Architecture::from_str("i686").unwrap().to_macho_cpu(),
+            Ok((7, 3))
+        );
+        assert_eq!(
+            Architecture::from_str("armv7a").unwrap().to_macho_cpu(),
+            Ok((12, 9))
+        );
+
+        assert!(Architecture::Riscv64(Riscv64Architecture::Riscv64)
+            .to_macho_cpu()
+            .is_err());
+        assert!(Architecture::Aarch64(Aarch64Architecture::Arm64ec)
+            .to_macho_cpu()
+            .is_err());
+        assert!(Architecture::from_macho_cpu(0x0100_0007, 4).is_err());
+        assert!(Architecture::from_macho_cpu(12, 10).is_err());
+        assert!(Architecture::from_macho_cpu(0x0100_0017, 0).is_err());
+    }
+
+    #[test]
+    fn bpf_versions() {
+        let t = Triple::from_str("bpfel-unknown-none").expect("can't parse target");
+        assert_eq!(t.architecture, Architecture::Bpfel(None));
+
+        let t = Triple::from_str("bpfebv3-unknown-none").expect("can't parse target");
+        assert_eq!(t.architecture, Architecture::Bpfeb(Some(BpfVersion::V3)));
+        assert_eq!(t.endianness(), Ok(Endianness::Big));
+        assert_eq!(t.to_string(), "bpfebv3-unknown-none");
+
+        // A plain `bpf` has the endianness of the host.
+        assert_eq!(
+            Architecture::from_str("bpfv4"),
+            Ok(Architecture::Bpf(Some(BpfVersion::V4)))
+        );
+        let host_endianness = if cfg!(target_endian = "big") {
+            Endianness::Big
+        } else {
+            Endianness::Little
+        };
+        assert_eq!(
+            Architecture::from_str("bpf").unwrap().endianness(),
+            Ok(host_endianness)
+        );
+        let t = Triple::from_str("bpf-unknown-none").expect("can't parse target");
+        assert_eq!(t.architecture, Architecture::Bpf(None));
+        assert_eq!(t.pointer_width(), Ok(PointerWidth::U64));
+        assert_eq!(t.to_string(), "bpf-unknown-none");
+        assert!(Architecture::from_str("bpfv5").is_err());
+        assert!(Architecture::from_str("bpfelv").is_err());
+
+        assert!(!BpfVersion::V1.supports_extended_jumps());
+        assert!(BpfVersion::V2.supports_extended_jumps());
+        assert!(!BpfVersion::V2.supports_atomics());
+        assert!(BpfVersion::V3.supports_atomics());
+        assert!(BpfVersion::V3.supports_jmp32());
+        assert!(!BpfVersion::V3.supports_signed_loads());
+        assert!(BpfVersion::V4.supports_signed_loads());
+    }
+
+    #[test]
+    fn llvm_arch_names() {
+        // `scripts/llvm-arch-names.txt` is a snapshot of the names that
+        // LLVM's `Triple::getArchTypeName` returns, made with
+        // `scripts/llvm-arch-names.sh` from the LLVM release named on its
+        // first line. Every name should be recognized, other than the ones
+        // listed here.
+        //
+        // This only checks one way: architectures that LLVM doesn't have, such
+        // as `asmjs`, `microblaze`, `microblazeel` and the Pulley ones, or that
+        // are newer than the snapshot, aren't caught here.
+        let unsupported = [
+            "aarch64_32",
+            "amdil",
+            "amdil64",
+            "csky",
+            "hsail",
+            "hsail64",
+            "nvptx",
+            "powerpcle",
+            "renderscript32",
+            "renderscript64",
+            "spir",
+            "spir64",
+            "spirv32",
+            "spirv64",
+            "thumb",
+            "xcore",
+        ];
+
+        let names = include_str!("../scripts/llvm-arch-names.txt");
+        let names = names
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with('#'));
+        for name in names.clone() {
+            assert_eq!(
+                Architecture::from_str(name).is_ok(),
+                !unsupported.contains(&name),
+                "{}",
+                name
+            );
+        }
+        for name in unsupported.iter() {
+            assert!(names.clone().any(|line| line == *name), "{}", name);
+        }
+    }
+
+    #[test]
+    fn llvm_embedded_archs() {
+        for (arch, endianness, pointer_width) in &[
+            ("arc", Endianness::Little, PointerWidth::U32),
+            ("kalimba", Endianness::Little, PointerWidth::U32),
+            ("lanai", Endianness::Big, PointerWidth::U32),
+            ("le32", Endianness::Little, PointerWidth::U32),
+            ("le64", Endianness::Little, PointerWidth::U64),
+            ("microblaze", Endianness::Big, PointerWidth::U32),
+            ("microblazeel", Endianness::Little, PointerWidth::U32),
+            ("r600", Endianness::Little, PointerWidth::U32),
+            ("shave", Endianness::Little, PointerWidth::U32),
+            ("sparcel", Endianness::Little, PointerWidth::U32),
+            ("tce", Endianness::Big, PointerWidth::U32),
+            ("tcele", Endianness::Little, PointerWidth::U32),
+            ("ve", Endianness::Little, PointerWidth::U64),
+        ] {
+            let a = Architecture::from_str(arch).expect("can't parse architecture");
+            assert_eq!(a.endianness(), Ok(*endianness), "{}", arch);
+            assert_eq!(a.pointer_width(), Ok(*pointer_width), "{}", arch);
+            assert_eq!(a.to_string(), *arch);
+        }
+
+        let t = Triple::from_str("systemz-ibm-linux").expect("can't parse target");
+        assert_eq!(t.architecture, Architecture::S390x);
+        assert_eq!(t.to_string(), "s390x-ibm-linux");
+    }
+
     #[test]
     fn default_format_to_elf() {
         let t = Triple::from_str("riscv64").expect("can't parse target");
@@ -2209,6 +4024,21 @@ mod tests {
         );
         assert_eq!(Triple::from_str("x86_64-apple-darwin23.0.0"), Ok(expected));
 
+        assert_eq!(
+            Triple::from_str("arm64-apple-driverkit21.0"),
+            Ok(Triple {
+                architecture: Architecture::Aarch64(Aarch64Architecture::Aarch64),
+                vendor: Vendor::Apple,
+                operating_system: OperatingSystem::DriverKit(Some(DeploymentTarget {
+                    major: 21,
+                    minor: 0,
+                    patch: 0,
+                })),
+                environment: Environment::Unknown,
+                binary_format: BinaryFormat::Macho,
+            })
+        );
+
         assert!(Triple::from_str("x86_64-apple-darwin.").is_err());
         assert!(Triple::from_str("x86_64-apple-darwin23.0.0.0").is_err());
     }
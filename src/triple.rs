@@ -3,8 +3,9 @@
 use crate::data_model::CDataModel;
 use crate::parse_error::ParseError;
 use crate::targets::{
-    default_binary_format, Architecture, ArmArchitecture, BinaryFormat, Environment,
-    OperatingSystem, Riscv32Architecture, Vendor,
+    default_binary_format, Aarch64Architecture, Architecture, ArmArchitecture, ArmFpu,
+    BinaryFormat, DeploymentTarget, Environment, OperatingSystem, Riscv32Architecture,
+    Riscv64Architecture, RiscvAbi, Vendor, X86_32Architecture, XtensaArchitecture,
 };
 #[cfg(not(feature = "std"))]
 use alloc::borrow::ToOwned;
@@ -76,6 +77,162 @@ pub enum CallingConvention {
     ///
     /// <https://developer.apple.com/documentation/xcode/writing_arm64_code_for_apple_platforms>
     AppleAarch64,
+
+    /// "XPLINK" (Extra Performance Linkage), which is used on z/OS.
+    Xplink,
+}
+
+/// A WASI preview, which selects the set of WASI interfaces available to a
+/// WebAssembly program.
+///
+/// Previews are ordered, so later previews compare greater than earlier ones.
+#[cfg_attr(feature = "rust_1_40", non_exhaustive)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum WasiPreview {
+    /// Preview 1, the core-module `wasi_snapshot_preview1` API. This is also
+    /// what the plain `wasi` operating system name means.
+    P1,
+    /// Preview 2, the first preview built on the component model.
+    P2,
+    /// Preview 3, which adds native async to the component model.
+    P3,
+}
+
+impl WasiPreview {
+    /// Return the preview number, such as `2` for preview 2.
+    pub fn number(self) -> u8 {
+        match self {
+            WasiPreview::P1 => 1,
+            WasiPreview::P2 => 2,
+            WasiPreview::P3 => 3,
+        }
+    }
+}
+
+/// The C runtime library that a Windows target links against.
+#[cfg_attr(feature = "rust_1_40", non_exhaustive)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WindowsCrt {
+    /// The Universal CRT and the Visual C++ runtime, as used by MSVC
+    /// targets. The Universal CRT is part of Windows 10 and later, so only
+    /// the Visual C++ runtime needs redistributing.
+    Ucrt,
+    /// The Universal CRT and the Visual C++ runtime, for MSVC targets that
+    /// also support Windows 7 and 8, where the Universal CRT must be
+    /// redistributed too.
+    UcrtRedistributable,
+    /// mingw-w64 on top of the system `msvcrt.dll`, as used by `windows-gnu`
+    /// targets.
+    MingwMsvcrt,
+    /// mingw-w64 on top of the Universal CRT, as used by `windows-gnullvm`
+    /// targets.
+    MingwUcrt,
+}
+
+/// The kind of host environment a WebAssembly target expects, which decides
+/// the runtime and linker to use.
+#[cfg_attr(feature = "rust_1_40", non_exhaustive)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WasmEnvironmentKind {
+    /// A JavaScript host, typically a browser, with imports and exports
+    /// generated by `wasm-bindgen`. This is what `wasm32-unknown-unknown`
+    /// is used for.
+    BrowserBindgen,
+    /// The Emscripten runtime, with its JavaScript glue and libc.
+    Emscripten,
+    /// A WASI runtime. See [`Triple::wasi_preview`] for which preview.
+    Wasi,
+    /// No particular host environment.
+    Bare,
+}
+
+/// An Apple platform, as identified by the `platform` field of Mach-O's
+/// `LC_BUILD_VERSION` load command.
+///
+/// Simulators and Mac Catalyst are platforms of their own here, where the
+/// triple expresses them through the environment.
+#[cfg_attr(feature = "rust_1_40", non_exhaustive)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum ApplePlatform {
+    MacOS,
+    Ios,
+    TvOS,
+    WatchOS,
+    BridgeOS,
+    MacCatalyst,
+    IosSimulator,
+    TvOSSimulator,
+    WatchOSSimulator,
+    DriverKit,
+    VisionOS,
+    VisionOSSimulator,
+    Firmware,
+    SepOS,
+}
+
+impl ApplePlatform {
+    /// Return the `PLATFORM_*` constant from `<mach-o/loader.h>`.
+    pub fn number(self) -> u32 {
+        match self {
+            ApplePlatform::MacOS => 1,
+            ApplePlatform::Ios => 2,
+            ApplePlatform::TvOS => 3,
+            ApplePlatform::WatchOS => 4,
+            ApplePlatform::BridgeOS => 5,
+            ApplePlatform::MacCatalyst => 6,
+            ApplePlatform::IosSimulator => 7,
+            ApplePlatform::TvOSSimulator => 8,
+            ApplePlatform::WatchOSSimulator => 9,
+            ApplePlatform::DriverKit => 10,
+            ApplePlatform::VisionOS => 11,
+            ApplePlatform::VisionOSSimulator => 12,
+            ApplePlatform::Firmware => 13,
+            ApplePlatform::SepOS => 14,
+        }
+    }
+
+    /// Return the platform for a `PLATFORM_*` constant, or `Err(())` if it
+    /// isn't a known platform.
+    pub fn from_number(number: u32) -> Result<Self, ()> {
+        Ok(match number {
+            1 => ApplePlatform::MacOS,
+            2 => ApplePlatform::Ios,
+            3 => ApplePlatform::TvOS,
+            4 => ApplePlatform::WatchOS,
+            5 => ApplePlatform::BridgeOS,
+            6 => ApplePlatform::MacCatalyst,
+            7 => ApplePlatform::IosSimulator,
+            8 => ApplePlatform::TvOSSimulator,
+            9 => ApplePlatform::WatchOSSimulator,
+            10 => ApplePlatform::DriverKit,
+            11 => ApplePlatform::VisionOS,
+            12 => ApplePlatform::VisionOSSimulator,
+            13 => ApplePlatform::Firmware,
+            14 => ApplePlatform::SepOS,
+            _ => return Err(()),
+        })
+    }
+
+    /// Return the name of the SDK for this platform, as accepted by
+    /// `xcrun --sdk`, or `Err(())` if there's no public SDK for it.
+    pub fn sdk_name(self) -> Result<&'static str, ()> {
+        Ok(match self {
+            ApplePlatform::MacOS | ApplePlatform::MacCatalyst => "macosx",
+            ApplePlatform::Ios => "iphoneos",
+            ApplePlatform::IosSimulator => "iphonesimulator",
+            ApplePlatform::TvOS => "appletvos",
+            ApplePlatform::TvOSSimulator => "appletvsimulator",
+            ApplePlatform::WatchOS => "watchos",
+            ApplePlatform::WatchOSSimulator => "watchsimulator",
+            ApplePlatform::VisionOS => "xros",
+            ApplePlatform::VisionOSSimulator => "xrsimulator",
+            ApplePlatform::DriverKit => "driverkit",
+            ApplePlatform::BridgeOS | ApplePlatform::Firmware | ApplePlatform::SepOS => {
+                return Err(())
+            }
+        })
+    }
 }
 
 /// An LLVM target "triple". Historically such things had three fields, though
@@ -107,13 +264,17 @@ impl Triple {
 
     /// Return the pointer width of this target's architecture.
     ///
-    /// This function is aware of x32 and ilp32 ABIs on 64-bit architectures.
+    /// This function is aware of x32 and ilp32 ABIs on 64-bit architectures,
+    /// and of the 32-bit pointers used by the PlayStation 3's Lv2.
     pub fn pointer_width(&self) -> Result<PointerWidth, ()> {
         // Some ABIs have a different pointer width than the CPU architecture.
         match self.environment {
             Environment::Gnux32 | Environment::GnuIlp32 => return Ok(PointerWidth::U32),
             _ => {}
         }
+        if self.operating_system == OperatingSystem::Lv2 {
+            return Ok(PointerWidth::U32);
+        }
 
         self.architecture.pointer_width()
     }
@@ -128,31 +289,222 @@ impl Triple {
             OperatingSystem::Aix
             | OperatingSystem::Bitrig
             | OperatingSystem::Cloudabi
-            | OperatingSystem::Dragonfly
-            | OperatingSystem::Freebsd
+            | OperatingSystem::Dragonfly(_)
+            | OperatingSystem::Freebsd(_)
+            | OperatingSystem::Freertos
             | OperatingSystem::Fuchsia
             | OperatingSystem::Haiku
             | OperatingSystem::Hermit
             | OperatingSystem::Hurd
             | OperatingSystem::L4re
             | OperatingSystem::Linux
-            | OperatingSystem::Netbsd
-            | OperatingSystem::Openbsd
+            | OperatingSystem::Liteos
+            | OperatingSystem::Lv2
+            | OperatingSystem::Managarm
+            | OperatingSystem::Netbsd(_)
+            | OperatingSystem::Nuttx
+            | OperatingSystem::Openbsd(_)
+            | OperatingSystem::Ps4
+            | OperatingSystem::Ps5
             | OperatingSystem::Redox
-            | OperatingSystem::Solaris => CallingConvention::SystemV,
+            | OperatingSystem::Rtems
+            | OperatingSystem::Serenity
+            | OperatingSystem::Solaris(_)
+            | OperatingSystem::Theseus
+            | OperatingSystem::Zephyr => CallingConvention::SystemV,
             OperatingSystem::Windows => CallingConvention::WindowsFastcall,
+            OperatingSystem::Zos => CallingConvention::Xplink,
+            OperatingSystem::None_ => match self.architecture {
+                // Bare-metal RISC-V follows the ELF psABI; see `riscv_abi` for
+                // the floating-point variant in use.
+                Architecture::Riscv32(_) | Architecture::Riscv64(_) => CallingConvention::SystemV,
+                _ => return Err(()),
+            },
             OperatingSystem::Nebulet
             | OperatingSystem::Emscripten
             | OperatingSystem::Wasi
+            | OperatingSystem::WasiP1
+            | OperatingSystem::WasiP2
+            | OperatingSystem::WasiP3
             | OperatingSystem::Unknown => match self.architecture {
-                Architecture::Wasm32 => CallingConvention::WasmBasicCAbi,
+                Architecture::Wasm32 | Architecture::Wasm64 => CallingConvention::WasmBasicCAbi,
                 _ => return Err(()),
             },
             _ => return Err(()),
         })
     }
 
+    /// Return the WASI preview targeted by this triple, or `Err(())` if this
+    /// isn't a WASI target.
+    pub fn wasi_preview(&self) -> Result<WasiPreview, ()> {
+        match self.operating_system {
+            OperatingSystem::Wasi | OperatingSystem::WasiP1 => Ok(WasiPreview::P1),
+            OperatingSystem::WasiP2 => Ok(WasiPreview::P2),
+            OperatingSystem::WasiP3 => Ok(WasiPreview::P3),
+            _ => Err(()),
+        }
+    }
+
+    /// Test if this triple targets WebAssembly components rather than core
+    /// modules.
+    ///
+    /// This is the case for WASI preview 2 and later. All other WebAssembly
+    /// targets, including `wasm32-wasip1`, produce core modules.
+    pub fn is_component_model(&self) -> bool {
+        self.wasi_preview()
+            .map(|preview| preview >= WasiPreview::P2)
+            .unwrap_or(false)
+    }
+
+    /// Return the C runtime library implied by this Windows target, or
+    /// `Err(())` if this isn't a Windows target or the environment doesn't
+    /// say.
+    pub fn windows_crt(&self) -> Result<WindowsCrt, ()> {
+        if self.operating_system != OperatingSystem::Windows {
+            return Err(());
+        }
+
+        Ok(match self.environment {
+            Environment::Msvc if self.vendor == Vendor::Win7 => WindowsCrt::UcrtRedistributable,
+            Environment::Msvc => WindowsCrt::Ucrt,
+            Environment::Gnu(_) => WindowsCrt::MingwMsvcrt,
+            Environment::GnuLlvm => WindowsCrt::MingwUcrt,
+            _ => return Err(()),
+        })
+    }
+
+    /// Return the kind of environment this WebAssembly target runs in, or
+    /// `Err(())` if this isn't a WebAssembly target.
+    pub fn wasm_environment_kind(&self) -> Result<WasmEnvironmentKind, ()> {
+        match self.architecture {
+            Architecture::Wasm32 | Architecture::Wasm64 => {}
+            _ => return Err(()),
+        }
+
+        Ok(match self.operating_system {
+            OperatingSystem::Emscripten => WasmEnvironmentKind::Emscripten,
+            OperatingSystem::Wasi
+            | OperatingSystem::WasiP1
+            | OperatingSystem::WasiP2
+            | OperatingSystem::WasiP3 => WasmEnvironmentKind::Wasi,
+            OperatingSystem::Unknown => WasmEnvironmentKind::BrowserBindgen,
+            _ => WasmEnvironmentKind::Bare,
+        })
+    }
+
+    /// Test if this triple needs the WebAssembly memory64 proposal, with
+    /// 64-bit linear memory addresses.
+    pub fn requires_memory64(&self) -> bool {
+        self.architecture == Architecture::Wasm64
+    }
+
+    /// Return the RISC-V ABI implied by this target triple, or `Err(())` if
+    /// this isn't a RISC-V target.
+    ///
+    /// The triple doesn't usually name the ABI, so it is inferred from the
+    /// ISA extensions in the architecture the same way `rustc` and `clang` do:
+    /// targets with the `D` extension use the double-precision float ABI,
+    /// targets with only `F` use the single-precision one, and RV32E targets
+    /// use `ilp32e`. A bare `riscv32` or `riscv64` is assumed to be `gc` when
+    /// it targets a hosted operating system, and soft-float otherwise.
+    pub fn riscv_abi(&self) -> Result<RiscvAbi, ()> {
+        let hosted = self.operating_system != OperatingSystem::None_
+            && self.operating_system != OperatingSystem::Unknown;
+
+        Ok(match self.architecture {
+            Architecture::Riscv32(riscv32) => match riscv32 {
+                Riscv32Architecture::Riscv32 if hosted => RiscvAbi::Ilp32d,
+                Riscv32Architecture::Riscv32gc => RiscvAbi::Ilp32d,
+                Riscv32Architecture::Riscv32imafc => RiscvAbi::Ilp32f,
+                Riscv32Architecture::Riscv32e
+                | Riscv32Architecture::Riscv32em
+                | Riscv32Architecture::Riscv32emc => RiscvAbi::Ilp32e,
+                Riscv32Architecture::Riscv32
+                | Riscv32Architecture::Riscv32i
+                | Riscv32Architecture::Riscv32im
+                | Riscv32Architecture::Riscv32ima
+                | Riscv32Architecture::Riscv32imac
+                | Riscv32Architecture::Riscv32imc => RiscvAbi::Ilp32,
+            },
+            Architecture::Riscv64(riscv64) => match riscv64 {
+                Riscv64Architecture::Riscv64 if hosted => RiscvAbi::Lp64d,
+                Riscv64Architecture::Riscv64gc | Riscv64Architecture::Riscv64a23 => RiscvAbi::Lp64d,
+                Riscv64Architecture::Riscv64 | Riscv64Architecture::Riscv64imac => RiscvAbi::Lp64,
+            },
+            _ => return Err(()),
+        })
+    }
+
+    /// Return the FPU implied by this ARM or AArch64 target triple, or
+    /// `Err(())` if this isn't an ARM target.
+    ///
+    /// `Ok(None)` means that the target doesn't use an FPU, as with the
+    /// soft-float `eabi` environments. Otherwise this is the FPU `rustc`
+    /// enables by default, which is suitable for passing to `-mfpu`.
+    pub fn arm_fpu(&self) -> Result<Option<ArmFpu>, ()> {
+        use ArmArchitecture::*;
+
+        let arm = match self.architecture {
+            Architecture::Arm(arm) => arm,
+            Architecture::Aarch64(_) => {
+                return Ok(match self.environment {
+                    Environment::Softfloat => None,
+                    _ => Some(ArmFpu::NeonFpArmv8),
+                })
+            }
+            _ => return Err(()),
+        };
+
+        let hard_float = match self.environment {
+            Environment::Eabihf
+            | Environment::Gnueabihf
+            | Environment::Musleabihf
+            | Environment::Uclibceabihf => true,
+            // Android uses the soft-float calling convention, but ARMv7 and
+            // later devices are required to have an FPU.
+            Environment::Androideabi(_) => {
+                let fpus = arm.has_fpu().unwrap_or(&[]);
+                fpus.contains(&ArmFpu::Vfpv3D16) || fpus.contains(&ArmFpu::Neon)
+            }
+            _ => match self.operating_system {
+                OperatingSystem::Freebsd(_) | OperatingSystem::Windows => true,
+                os => os.is_like_darwin(),
+            },
+        };
+        if !hard_float {
+            return Ok(None);
+        }
+
+        let darwin_or_windows = self.operating_system.is_like_darwin()
+            || self.operating_system == OperatingSystem::Windows;
+
+        Ok(match arm {
+            // A hard-float ABI needs an FPU, and VFPv2 is the oldest one.
+            Arm | Armeb | Thumbeb => Some(ArmFpu::Vfpv2),
+            Armv4 | Armv4t | Thumbv4t | Armv6m | Thumbv6m | Armv7m | Thumbv7m | Armv8mBase
+            | Thumbv8mBase => None,
+            Armv5t | Armv5te | Armv5tej | Thumbv5te | Armv6 | Armv6j | Armv6k | Armv6z
+            | Armv6kz | Armv6t2 => Some(ArmFpu::Vfpv2),
+            Armv7 | Armv7a | Thumbv7a if darwin_or_windows => Some(ArmFpu::Neon),
+            Armv7 | Armv7a | Thumbv7a => Some(ArmFpu::Vfpv3D16),
+            Armv7ve => Some(ArmFpu::Vfpv4D16),
+            Armv7s | Armv7k => Some(ArmFpu::NeonVfpv4),
+            Thumbv7neon => Some(ArmFpu::Neon),
+            Armv7r | Armebv7r => Some(ArmFpu::Vfpv3D16),
+            Thumbv7em => Some(ArmFpu::Fpv4SpD16),
+            Armv8mMain | Thumbv8mMain | Armv8r => Some(ArmFpu::Fpv5SpD16),
+            Armv8 | Armv8a | Armv8_1a | Armv8_2a | Armv8_3a | Armv8_4a | Armv8_5a => {
+                Some(ArmFpu::NeonFpArmv8)
+            }
+        })
+    }
+
     /// The C data model for a given target. If the model is not known, returns `Err(())`.
+    ///
+    /// This only describes the sizes of `int`, `long`, `long long` and
+    /// pointers. Differences in other types aren't modelled, such as the 64-bit
+    /// `long double` on the PlayStation 4 and 5, which are reported as plain
+    /// `LP64`.
     pub fn data_model(&self) -> Result<CDataModel, ()> {
         match self.pointer_width()? {
             PointerWidth::U64 => {
@@ -161,6 +513,7 @@ impl Triple {
                 } else if self.default_calling_convention() == Ok(CallingConvention::SystemV)
                     || self.architecture == Architecture::Wasm64
                     || self.default_calling_convention() == Ok(CallingConvention::AppleAarch64)
+                    || self.default_calling_convention() == Ok(CallingConvention::Xplink)
                 {
                     Ok(CDataModel::LP64)
                 } else {
@@ -170,6 +523,7 @@ impl Triple {
             PointerWidth::U32 => {
                 if self.operating_system == OperatingSystem::Windows
                     || self.default_calling_convention() == Ok(CallingConvention::SystemV)
+                    || self.default_calling_convention() == Ok(CallingConvention::AppleAarch64)
                     || self.architecture == Architecture::Wasm32
                 {
                     Ok(CDataModel::ILP32)
@@ -185,6 +539,231 @@ impl Triple {
         }
     }
 
+    /// Return this triple with a generic `darwin` operating system replaced
+    /// by the concrete Apple OS, such as `x86_64-apple-macosx14` for
+    /// `x86_64-apple-darwin23`.
+    ///
+    /// Triples that already name a concrete Apple OS are returned unchanged.
+    /// Returns `Err(())` if this isn't an Apple OS, if the architecture and
+    /// environment don't determine which Apple OS is meant, or if the Darwin
+    /// version doesn't map to a release of that OS.
+    pub fn normalize_apple_os(&self) -> Result<Self, ()> {
+        let darwin = match self.operating_system {
+            OperatingSystem::Darwin(darwin) => darwin,
+            os if os.is_like_darwin() => return Ok(self.clone()),
+            _ => return Err(()),
+        };
+
+        let os = match (self.architecture, self.environment) {
+            // Mac Catalyst triples are spelled as iOS.
+            (_, Environment::Macabi) => OperatingSystem::IOS(None),
+            (Architecture::Arm(ArmArchitecture::Armv7k), Environment::Unknown) => {
+                OperatingSystem::WatchOS(None)
+            }
+            (Architecture::Arm(ArmArchitecture::Armv7), Environment::Unknown)
+            | (Architecture::Arm(ArmArchitecture::Armv7s), Environment::Unknown) => {
+                OperatingSystem::IOS(None)
+            }
+            (Architecture::Aarch64(Aarch64Architecture::Arm64_32), Environment::Unknown) => {
+                OperatingSystem::WatchOS(None)
+            }
+            (Architecture::Aarch64(Aarch64Architecture::Aarch64), Environment::Unknown)
+            | (Architecture::Aarch64(Aarch64Architecture::Arm64e), Environment::Unknown)
+            | (Architecture::X86_32(_), Environment::Unknown)
+            | (Architecture::X86_64, Environment::Unknown)
+            | (Architecture::X86_64h, Environment::Unknown)
+            | (Architecture::Powerpc, Environment::Unknown)
+            | (Architecture::Powerpc64, Environment::Unknown) => OperatingSystem::MacOSX(None),
+            _ => return Err(()),
+        };
+        let os = match darwin {
+            Some(darwin) => os.with_darwin_version(darwin)?,
+            None => os,
+        };
+
+        Ok(Self {
+            operating_system: os,
+            ..self.clone()
+        })
+    }
+
+    /// Return the Apple platform for this triple, as needed by Mach-O's
+    /// `LC_BUILD_VERSION`, or `Err(())` if this isn't an Apple target or
+    /// the platform can't be determined.
+    pub fn apple_platform(&self) -> Result<ApplePlatform, ()> {
+        let normalized = self.normalize_apple_os()?;
+
+        // There are no x86 Apple devices besides Macs, so x86 triples for the
+        // other Apple OSes always mean the simulator.
+        let simulator = normalized.environment == Environment::Sim
+            || (normalized.environment == Environment::Unknown
+                && (normalized.architecture == Architecture::X86_64
+                    || normalized.architecture == Architecture::X86_32(X86_32Architecture::I386)
+                    || normalized.architecture == Architecture::X86_32(X86_32Architecture::I686)));
+
+        Ok(
+            match (normalized.operating_system, normalized.environment) {
+                (OperatingSystem::MacOSX(_), Environment::Unknown) => ApplePlatform::MacOS,
+                (OperatingSystem::IOS(_), Environment::Macabi) => ApplePlatform::MacCatalyst,
+                (_, Environment::Macabi) => return Err(()),
+                (OperatingSystem::IOS(_), _) if simulator => ApplePlatform::IosSimulator,
+                (OperatingSystem::TvOS(_), _) if simulator => ApplePlatform::TvOSSimulator,
+                (OperatingSystem::WatchOS(_), _) if simulator => ApplePlatform::WatchOSSimulator,
+                (OperatingSystem::VisionOS(_), _) | (OperatingSystem::XROS(_), _) if simulator => {
+                    ApplePlatform::VisionOSSimulator
+                }
+                (_, Environment::Unknown) => match normalized.operating_system {
+                    OperatingSystem::IOS(_) => ApplePlatform::Ios,
+                    OperatingSystem::TvOS(_) => ApplePlatform::TvOS,
+                    OperatingSystem::WatchOS(_) => ApplePlatform::WatchOS,
+                    OperatingSystem::VisionOS(_) | OperatingSystem::XROS(_) => {
+                        ApplePlatform::VisionOS
+                    }
+                    OperatingSystem::BridgeOS(_) => ApplePlatform::BridgeOS,
+                    OperatingSystem::DriverKit(_) => ApplePlatform::DriverKit,
+                    OperatingSystem::Firmware(_) => ApplePlatform::Firmware,
+                    _ => return Err(()),
+                },
+                _ => return Err(()),
+            },
+        )
+    }
+
+    /// Test if this triple is for an Apple simulator, either explicitly with
+    /// the `sim` environment, or implicitly as x86 code for an Apple OS that
+    /// only runs on Arm hardware, as in `x86_64-apple-ios`.
+    pub fn is_simulator(&self) -> bool {
+        if self.environment == Environment::Sim {
+            return true;
+        }
+
+        let platform = self.apple_platform();
+        platform == Ok(ApplePlatform::IosSimulator)
+            || platform == Ok(ApplePlatform::TvOSSimulator)
+            || platform == Ok(ApplePlatform::WatchOSSimulator)
+            || platform == Ok(ApplePlatform::VisionOSSimulator)
+    }
+
+    /// Test if this triple is for Mac Catalyst, which runs iOS apps on macOS.
+    pub fn is_mac_catalyst(&self) -> bool {
+        self.environment == Environment::Macabi
+    }
+
+    /// Return the name of the Apple SDK to build this triple with, such as
+    /// `iphonesimulator`, or `Err(())` if there isn't one.
+    pub fn apple_sdk_name(&self) -> Result<&'static str, ()> {
+        self.apple_platform()?.sdk_name()
+    }
+
+    /// Return the oldest Apple OS release that `rustc` supports for this
+    /// architecture and environment, such as macOS 10.12 for
+    /// `x86_64-apple-darwin` and macOS 11.0 for `aarch64-apple-darwin`, or
+    /// `Err(())` if this isn't an Apple target with a known minimum.
+    pub fn minimum_deployment_target(&self) -> Result<DeploymentTarget, ()> {
+        let arm64 = self.architecture == Architecture::Aarch64(Aarch64Architecture::Aarch64)
+            || self.architecture == Architecture::Aarch64(Aarch64Architecture::Arm64e);
+        let arm64e = self.architecture == Architecture::Aarch64(Aarch64Architecture::Arm64e);
+
+        let (major, minor) = match self.apple_platform()? {
+            ApplePlatform::MacOS if arm64 => (11, 0),
+            ApplePlatform::MacCatalyst if arm64 => (14, 0),
+            ApplePlatform::IosSimulator | ApplePlatform::TvOSSimulator if arm64 => (14, 0),
+            ApplePlatform::WatchOSSimulator if arm64 => (7, 0),
+            ApplePlatform::Ios if arm64e => (14, 0),
+            // Mac Catalyst defaults to 13.1 in Clang.
+            ApplePlatform::MacCatalyst => (13, 1),
+            ApplePlatform::MacOS => (10, 12),
+            ApplePlatform::Ios | ApplePlatform::IosSimulator => (10, 0),
+            ApplePlatform::TvOS | ApplePlatform::TvOSSimulator => (10, 0),
+            ApplePlatform::WatchOS | ApplePlatform::WatchOSSimulator => (5, 0),
+            ApplePlatform::VisionOS | ApplePlatform::VisionOSSimulator => (1, 0),
+            ApplePlatform::DriverKit => (19, 0),
+            ApplePlatform::BridgeOS | ApplePlatform::Firmware | ApplePlatform::SepOS => {
+                return Err(())
+            }
+        };
+
+        Ok(DeploymentTarget {
+            major,
+            minor,
+            patch: 0,
+        })
+    }
+
+    /// Return the Apple OS release that `rustc` targets by default, such as
+    /// macOS 10.12 for `x86_64-apple-darwin`, or `Err(())` if this isn't an
+    /// Apple target with a known default.
+    ///
+    /// This is the same as the
+    /// [`minimum_deployment_target`](Self::minimum_deployment_target).
+    pub fn default_deployment_target(&self) -> Result<DeploymentTarget, ()> {
+        // `rustc` targets its minimum unless it's told otherwise.
+        self.minimum_deployment_target()
+    }
+
+    /// Return the name of the environment variable that overrides the
+    /// deployment target, such as `MACOSX_DEPLOYMENT_TARGET`, or `Err(())` if
+    /// there isn't one for this target.
+    pub fn deployment_target_env_var(&self) -> Result<&'static str, ()> {
+        Ok(match self.apple_platform()? {
+            ApplePlatform::MacOS => "MACOSX_DEPLOYMENT_TARGET",
+            // Mac Catalyst is versioned like iOS.
+            ApplePlatform::Ios | ApplePlatform::IosSimulator | ApplePlatform::MacCatalyst => {
+                "IPHONEOS_DEPLOYMENT_TARGET"
+            }
+            ApplePlatform::TvOS | ApplePlatform::TvOSSimulator => "TVOS_DEPLOYMENT_TARGET",
+            ApplePlatform::WatchOS | ApplePlatform::WatchOSSimulator => "WATCHOS_DEPLOYMENT_TARGET",
+            ApplePlatform::VisionOS | ApplePlatform::VisionOSSimulator => "XROS_DEPLOYMENT_TARGET",
+            _ => return Err(()),
+        })
+    }
+
+    /// Return the deployment target to build this triple for.
+    ///
+    /// A version in the triple itself, as in `arm64-apple-macosx13.0`, takes
+    /// precedence. Otherwise `env_var` is called with the name from
+    /// [`deployment_target_env_var`](Self::deployment_target_env_var) and may
+    /// return its value, like `|name| std::env::var(name).ok()`, and
+    /// otherwise the [default](Self::default_deployment_target) is used.
+    /// Versions older than the
+    /// [minimum](Self::minimum_deployment_target) are raised to it.
+    ///
+    /// Returns `Err(())` if this isn't an Apple target, or if the variable
+    /// isn't a valid version.
+    pub fn resolve_deployment_target<F, S>(&self, env_var: F) -> Result<DeploymentTarget, ()>
+    where
+        F: FnOnce(&str) -> Option<S>,
+        S: AsRef<str>,
+    {
+        let minimum = self.minimum_deployment_target()?;
+
+        let explicit = match self.normalize_apple_os()?.operating_system {
+            OperatingSystem::DriverKit(version)
+            | OperatingSystem::IOS(version)
+            | OperatingSystem::MacOSX(version)
+            | OperatingSystem::TvOS(version)
+            | OperatingSystem::VisionOS(version)
+            | OperatingSystem::WatchOS(version)
+            | OperatingSystem::XROS(version) => version,
+            _ => None,
+        };
+        let requested = match (explicit, self.deployment_target_env_var()) {
+            (Some(version), _) => Some(version),
+            (None, Ok(name)) => match env_var(name) {
+                // An empty variable is the same as an unset one.
+                Some(ref value) if value.as_ref().is_empty() => None,
+                Some(value) => Some(value.as_ref().parse()?),
+                None => None,
+            },
+            (None, Err(())) => None,
+        };
+
+        match requested {
+            Some(version) => Ok(version.max(minimum)),
+            None => self.default_deployment_target(),
+        }
+    }
+
     /// Return a `Triple` with all unknown fields.
     pub fn unknown() -> Self {
         Self {
@@ -210,12 +789,16 @@ impl fmt::Display for Triple {
             && (self.environment != Environment::HermitKernel
                 && self.environment != Environment::LinuxKernel)
             && ((self.operating_system == OperatingSystem::Linux
-                && (self.environment == Environment::Android
-                    || self.environment == Environment::Androideabi
+                && (self.environment.android_api_level().is_ok()
                     || self.environment == Environment::Kernel))
                 || self.operating_system == OperatingSystem::Wasi
                 || self.operating_system == OperatingSystem::WasiP1
                 || self.operating_system == OperatingSystem::WasiP2
+                || self.operating_system == OperatingSystem::WasiP3
+                || ((self.operating_system == OperatingSystem::Nuttx
+                    || self.operating_system == OperatingSystem::Rtems)
+                    && (self.environment == Environment::Eabi
+                        || self.environment == Environment::Eabihf))
                 || (self.operating_system == OperatingSystem::None_
                     && (self.architecture == Architecture::Arm(ArmArchitecture::Armv4t)
                         || self.architecture == Architecture::Arm(ArmArchitecture::Armv5te)
@@ -232,14 +815,24 @@ impl fmt::Display for Triple {
                         || self.architecture == Architecture::Arm(ArmArchitecture::Thumbv8mMain)
                         || self.architecture == Architecture::Msp430)))
         {
-            // As a special case, omit the vendor for Android, Wasi, and sometimes
-            // None_, depending on the hardware architecture. This logic is entirely
-            // ad-hoc, and is just sufficient to handle the current set of recognized
-            // triples.
+            // As a special case, omit the vendor for Android, Wasi, ARM NuttX
+            // and RTEMS, and sometimes None_, depending on the hardware
+            // architecture. This logic is entirely ad-hoc, and is just
+            // sufficient to handle the current set of recognized triples.
             write!(f, "-{}", self.operating_system)?;
         } else if self.architecture.is_clever() && self.operating_system == OperatingSystem::Unknown
         {
             write!(f, "-{}", self.vendor)?;
+        } else if let (Architecture::XTensa(core), Vendor::Espressif) =
+            (self.architecture, &self.vendor)
+        {
+            // Print the core in the vendor's place; a generic core has no
+            // name of its own, so it keeps the vendor.
+            if core == XtensaArchitecture::Xtensa {
+                write!(f, "-{}-{}", self.vendor, self.operating_system)?;
+            } else {
+                write!(f, "-{}-{}", core, self.operating_system)?;
+            }
         } else {
             write!(f, "-{}-{}", self.vendor, self.operating_system)?;
         }
@@ -266,11 +859,52 @@ impl fmt::Display for Triple {
     }
 }
 
+/// Check that the simulator and Mac Catalyst environments, which only exist
+/// for some Apple OSes, are used with one of them.
+fn check_apple_environment(
+    operating_system: OperatingSystem,
+    environment: Environment,
+) -> Result<(), ()> {
+    match (environment, operating_system) {
+        (Environment::Sim, OperatingSystem::BridgeOS(_))
+        | (Environment::Sim, OperatingSystem::DriverKit(_))
+        | (Environment::Sim, OperatingSystem::Firmware(_))
+        | (Environment::Sim, OperatingSystem::MacOSX(_)) => Err(()),
+        (Environment::Sim, os) if !os.is_like_darwin() => Err(()),
+        (Environment::Macabi, OperatingSystem::Darwin(_))
+        | (Environment::Macabi, OperatingSystem::IOS(_)) => Ok(()),
+        (Environment::Macabi, _) => Err(()),
+        _ => Ok(()),
+    }
+}
+
+/// Check that a libc version, as in `gnu.2.17`, is only used with an OS where
+/// glibc or musl provide the C library.
+fn check_libc_environment(
+    operating_system: OperatingSystem,
+    environment: Environment,
+) -> Result<(), ()> {
+    match environment.libc_version() {
+        Ok(Some(_)) => match operating_system {
+            OperatingSystem::Hurd | OperatingSystem::Linux => Ok(()),
+            _ => Err(()),
+        },
+        _ => Ok(()),
+    }
+}
+
 fn show_binary_format_with_no_os(triple: &Triple) -> bool {
     if triple.binary_format == BinaryFormat::Unknown {
         return false;
     }
 
+    // `rustc`'s RISC-V NuttX triples spell out the binary format.
+    match (triple.operating_system, triple.architecture) {
+        (OperatingSystem::Nuttx, Architecture::Riscv32(_))
+        | (OperatingSystem::Nuttx, Architecture::Riscv64(_)) => return true,
+        _ => {}
+    }
+
     #[cfg(feature = "arch_zkasm")]
     {
         if triple.architecture == Architecture::ZkAsm {
@@ -317,11 +951,28 @@ impl FromStr for Triple {
 
         let mut has_vendor = false;
         let mut has_operating_system = false;
-        if let Some(s) = current_part {
-            if let Ok(vendor) = Vendor::from_str(s) {
-                has_vendor = true;
-                result.vendor = vendor;
-                current_part = parts.next();
+        if let (Architecture::XTensa(XtensaArchitecture::Xtensa), Some(s)) =
+            (result.architecture, current_part)
+        {
+            // A core name in the vendor position, as in `xtensa-esp32-none-elf`,
+            // refines the architecture and implies the Espressif vendor.
+            match XtensaArchitecture::from_str(s) {
+                Ok(core) if core != XtensaArchitecture::Xtensa => {
+                    has_vendor = true;
+                    result.architecture = Architecture::XTensa(core);
+                    result.vendor = Vendor::Espressif;
+                    current_part = parts.next();
+                }
+                _ => {}
+            }
+        }
+        if !has_vendor {
+            if let Some(s) = current_part {
+                if let Ok(vendor) = Vendor::from_str(s) {
+                    has_vendor = true;
+                    result.vendor = vendor;
+                    current_part = parts.next();
+                }
             }
         }
 
@@ -340,6 +991,11 @@ impl FromStr for Triple {
         if !has_environment {
             if let Some(s) = current_part {
                 if let Ok(environment) = Environment::from_str(s) {
+                    if check_apple_environment(result.operating_system, environment).is_err()
+                        || check_libc_environment(result.operating_system, environment).is_err()
+                    {
+                        return Err(ParseError::UnrecognizedEnvironment(s.to_owned()));
+                    }
                     has_environment = true;
                     result.environment = environment;
                     current_part = parts.next();
@@ -444,6 +1100,7 @@ macro_rules! triple {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::string::ToString;
 
     #[test]
     fn parse_errors() {
@@ -524,6 +1181,655 @@ mod tests {
         }
     }
 
+    #[test]
+    fn riscv_abi() {
+        for (triple, abi) in &[
+            ("riscv64gc-unknown-linux-gnu", RiscvAbi::Lp64d),
+            ("riscv64-linux-android", RiscvAbi::Lp64d),
+            ("riscv64a23-unknown-linux-gnu", RiscvAbi::Lp64d),
+            ("riscv64imac-unknown-none-elf", RiscvAbi::Lp64),
+            ("riscv64-unknown-none-elf", RiscvAbi::Lp64),
+            ("riscv32gc-unknown-linux-gnu", RiscvAbi::Ilp32d),
+            ("riscv32imac-unknown-none-elf", RiscvAbi::Ilp32),
+            ("riscv32imafc-unknown-none-elf", RiscvAbi::Ilp32f),
+            ("riscv32imc-esp-espidf", RiscvAbi::Ilp32),
+            ("riscv32emc-unknown-none-elf", RiscvAbi::Ilp32e),
+        ] {
+            let triple = Triple::from_str(triple).unwrap();
+            assert_eq!(triple.riscv_abi(), Ok(*abi), "{}", triple);
+            assert_eq!(
+                abi.pointer_width(),
+                triple.pointer_width().unwrap(),
+                "{}",
+                triple
+            );
+        }
+
+        assert_eq!(
+            Triple::from_str("x86_64-unknown-linux-gnu")
+                .unwrap()
+                .riscv_abi(),
+            Err(())
+        );
+
+        // Bare-metal RISC-V has a known calling convention and data model.
+        let triple = Triple::from_str("riscv32imac-unknown-none-elf").unwrap();
+        assert_eq!(
+            triple.default_calling_convention(),
+            Ok(CallingConvention::SystemV)
+        );
+        assert_eq!(triple.data_model(), Ok(CDataModel::ILP32));
+        let triple = Triple::from_str("riscv64gc-unknown-none-elf").unwrap();
+        assert_eq!(triple.data_model(), Ok(CDataModel::LP64));
+    }
+
+    #[test]
+    fn arm_fpu() {
+        for (triple, fpu) in &[
+            ("thumbv7em-none-eabi", None),
+            ("thumbv7em-none-eabihf", Some(ArmFpu::Fpv4SpD16)),
+            ("thumbv6m-none-eabi", None),
+            ("thumbv8m.main-none-eabihf", Some(ArmFpu::Fpv5SpD16)),
+            ("armv7r-none-eabihf", Some(ArmFpu::Vfpv3D16)),
+            ("armv7a-none-eabi", None),
+            ("armv7a-none-eabihf", Some(ArmFpu::Vfpv3D16)),
+            ("arm-unknown-linux-gnueabi", None),
+            ("arm-unknown-linux-gnueabihf", Some(ArmFpu::Vfpv2)),
+            ("arm-linux-androideabi", None),
+            ("armv7-linux-androideabi", Some(ArmFpu::Vfpv3D16)),
+            ("armv7-unknown-linux-gnueabihf", Some(ArmFpu::Vfpv3D16)),
+            ("thumbv7neon-unknown-linux-gnueabihf", Some(ArmFpu::Neon)),
+            ("thumbv7neon-linux-androideabi", Some(ArmFpu::Neon)),
+            ("armv7s-apple-ios", Some(ArmFpu::NeonVfpv4)),
+            ("thumbv7a-pc-windows-msvc", Some(ArmFpu::Neon)),
+            ("aarch64-unknown-linux-gnu", Some(ArmFpu::NeonFpArmv8)),
+            ("aarch64-unknown-none-softfloat", None),
+        ] {
+            let triple = Triple::from_str(triple).unwrap();
+            assert_eq!(triple.arm_fpu(), Ok(*fpu), "{}", triple);
+            if let (Architecture::Arm(arm), Some(fpu)) = (triple.architecture, fpu) {
+                if let Ok(fpus) = arm.has_fpu() {
+                    assert!(fpus.contains(fpu), "{}", triple);
+                }
+                assert!(!arm.has_neon() || fpu.has_neon(), "{}", triple);
+            }
+        }
+
+        assert_eq!(ArmArchitecture::Thumbv7m.has_fpu(), Ok(&[][..]));
+        assert_eq!(ArmArchitecture::Arm.has_fpu(), Err(()));
+        assert!(ArmArchitecture::Thumbv7neon.has_neon());
+        assert!(!ArmArchitecture::Armv7a.has_neon());
+        assert_eq!(
+            Triple::from_str("x86_64-unknown-linux-gnu")
+                .unwrap()
+                .arm_fpu(),
+            Err(())
+        );
+    }
+
+    #[test]
+    fn os_defaults() {
+        // The binary format, calling convention and C data model that each
+        // operating system implies. The PlayStation 4 and 5's 64-bit `long
+        // double` isn't part of the data model.
+        for (triple, binary_format, calling_convention, data_model) in &[
+            (
+                "x86_64-scei-ps4",
+                BinaryFormat::Elf,
+                CallingConvention::SystemV,
+                CDataModel::LP64,
+            ),
+            (
+                "x86_64-sie-ps5",
+                BinaryFormat::Elf,
+                CallingConvention::SystemV,
+                CDataModel::LP64,
+            ),
+            (
+                "powerpc64-scei-lv2",
+                BinaryFormat::Elf,
+                CallingConvention::SystemV,
+                CDataModel::ILP32,
+            ),
+            (
+                "s390x-ibm-zos",
+                BinaryFormat::Goff,
+                CallingConvention::Xplink,
+                CDataModel::LP64,
+            ),
+            (
+                "s390x-ibm-zos-elf",
+                BinaryFormat::Elf,
+                CallingConvention::Xplink,
+                CDataModel::LP64,
+            ),
+            (
+                "arm64-apple-driverkit21.0",
+                BinaryFormat::Macho,
+                CallingConvention::AppleAarch64,
+                CDataModel::LP64,
+            ),
+            (
+                "x86_64-apple-driverkit19.0",
+                BinaryFormat::Macho,
+                CallingConvention::SystemV,
+                CDataModel::LP64,
+            ),
+            (
+                "aarch64-apple-bridgeos",
+                BinaryFormat::Macho,
+                CallingConvention::AppleAarch64,
+                CDataModel::LP64,
+            ),
+            (
+                "aarch64-apple-firmware",
+                BinaryFormat::Macho,
+                CallingConvention::AppleAarch64,
+                CDataModel::LP64,
+            ),
+            (
+                "armv7-rtems-eabihf",
+                BinaryFormat::Elf,
+                CallingConvention::SystemV,
+                CDataModel::ILP32,
+            ),
+            (
+                "sparc-unknown-rtems",
+                BinaryFormat::Elf,
+                CallingConvention::SystemV,
+                CDataModel::ILP32,
+            ),
+            (
+                "thumbv7em-unknown-zephyr-eabihf",
+                BinaryFormat::Elf,
+                CallingConvention::SystemV,
+                CDataModel::ILP32,
+            ),
+            (
+                "thumbv7m-nuttx-eabi",
+                BinaryFormat::Elf,
+                CallingConvention::SystemV,
+                CDataModel::ILP32,
+            ),
+            (
+                "riscv32imac-unknown-nuttx-elf",
+                BinaryFormat::Elf,
+                CallingConvention::SystemV,
+                CDataModel::ILP32,
+            ),
+            (
+                "aarch64-unknown-nuttx",
+                BinaryFormat::Elf,
+                CallingConvention::SystemV,
+                CDataModel::LP64,
+            ),
+            (
+                "arm-unknown-liteos",
+                BinaryFormat::Elf,
+                CallingConvention::SystemV,
+                CDataModel::ILP32,
+            ),
+            (
+                "x86_64-unknown-freertos",
+                BinaryFormat::Elf,
+                CallingConvention::SystemV,
+                CDataModel::LP64,
+            ),
+            (
+                "x86_64-unknown-managarm-mlibc",
+                BinaryFormat::Elf,
+                CallingConvention::SystemV,
+                CDataModel::LP64,
+            ),
+            (
+                "x86_64-pc-serenity",
+                BinaryFormat::Elf,
+                CallingConvention::SystemV,
+                CDataModel::LP64,
+            ),
+            (
+                "aarch64-unknown-serenity",
+                BinaryFormat::Elf,
+                CallingConvention::SystemV,
+                CDataModel::LP64,
+            ),
+            (
+                "x86_64-unknown-theseus",
+                BinaryFormat::Elf,
+                CallingConvention::SystemV,
+                CDataModel::LP64,
+            ),
+            (
+                "riscv64gc-unknown-redox",
+                BinaryFormat::Elf,
+                CallingConvention::SystemV,
+                CDataModel::LP64,
+            ),
+        ] {
+            let triple = Triple::from_str(triple).unwrap();
+            assert_eq!(triple.binary_format, *binary_format, "{}", triple);
+            assert_eq!(
+                triple.default_calling_convention(),
+                Ok(*calling_convention),
+                "{}",
+                triple
+            );
+            assert_eq!(triple.data_model(), Ok(*data_model), "{}", triple);
+        }
+
+        // RISC-V NuttX triples spell out the binary format even when it's
+        // implied.
+        let triple = Triple::from_str("riscv32imc-unknown-nuttx").unwrap();
+        assert_eq!(triple.to_string(), "riscv32imc-unknown-nuttx-elf");
+    }
+
+    #[test]
+    fn wasi_previews() {
+        for (triple, preview, component) in &[
+            ("wasm32-wasi", WasiPreview::P1, false),
+            ("wasm32-wasip1", WasiPreview::P1, false),
+            ("wasm32-wasip1-threads", WasiPreview::P1, false),
+            ("wasm32-wasip2", WasiPreview::P2, true),
+            ("wasm32-wasip3", WasiPreview::P3, true),
+            ("wasm64-wasi", WasiPreview::P1, false),
+        ] {
+            let triple = Triple::from_str(triple).unwrap();
+            assert_eq!(triple.wasi_preview(), Ok(*preview), "{}", triple);
+            assert_eq!(triple.is_component_model(), *component, "{}", triple);
+        }
+
+        // Every WASI preview is WebAssembly with the basic C ABI. The
+        // `wasip1` and `wasip2` names used to default to ELF, with no known
+        // calling convention.
+        for triple in &[
+            "wasm32-wasi",
+            "wasm32-wasip1",
+            "wasm32-wasip1-threads",
+            "wasm32-wasip2",
+            "wasm32-wasip3",
+        ] {
+            let triple = Triple::from_str(triple).unwrap();
+            assert_eq!(triple.binary_format, BinaryFormat::Wasm, "{}", triple);
+            assert_eq!(
+                triple.default_calling_convention(),
+                Ok(CallingConvention::WasmBasicCAbi),
+                "{}",
+                triple
+            );
+        }
+
+        let triple = Triple::from_str("wasm32-unknown-unknown").unwrap();
+        assert_eq!(triple.wasi_preview(), Err(()));
+        assert!(!triple.is_component_model());
+        assert_eq!(WasiPreview::P3.number(), 3);
+        assert!(WasiPreview::P1 < WasiPreview::P2);
+    }
+
+    #[test]
+    fn wasm_environments() {
+        for (triple, kind, memory64) in &[
+            (
+                "wasm32-unknown-unknown",
+                WasmEnvironmentKind::BrowserBindgen,
+                false,
+            ),
+            (
+                "wasm64-unknown-unknown",
+                WasmEnvironmentKind::BrowserBindgen,
+                true,
+            ),
+            (
+                "wasm32-unknown-emscripten",
+                WasmEnvironmentKind::Emscripten,
+                false,
+            ),
+            ("wasm32-wasip1", WasmEnvironmentKind::Wasi, false),
+            ("wasm32-wasip2", WasmEnvironmentKind::Wasi, false),
+            ("wasm64-wasi", WasmEnvironmentKind::Wasi, true),
+            ("wasm32-unknown-none", WasmEnvironmentKind::Bare, false),
+        ] {
+            let triple = Triple::from_str(triple).unwrap();
+            assert_eq!(triple.wasm_environment_kind(), Ok(*kind), "{}", triple);
+            assert_eq!(triple.requires_memory64(), *memory64, "{}", triple);
+        }
+
+        for (triple, data_model) in &[
+            ("wasm32-unknown-unknown", CDataModel::ILP32),
+            ("wasm64-unknown-unknown", CDataModel::LP64),
+            ("wasm64-wasi", CDataModel::LP64),
+        ] {
+            let triple = Triple::from_str(triple).unwrap();
+            assert_eq!(
+                triple.default_calling_convention(),
+                Ok(CallingConvention::WasmBasicCAbi),
+                "{}",
+                triple
+            );
+            assert_eq!(triple.data_model(), Ok(*data_model), "{}", triple);
+        }
+
+        assert_eq!(
+            Triple::from_str("asmjs-unknown-emscripten")
+                .unwrap()
+                .wasm_environment_kind(),
+            Err(())
+        );
+    }
+
+    #[test]
+    fn windows() {
+        for (triple, crt) in &[
+            ("x86_64-pc-windows-msvc", WindowsCrt::Ucrt),
+            ("aarch64-uwp-windows-msvc", WindowsCrt::Ucrt),
+            ("arm64ec-pc-windows-msvc", WindowsCrt::Ucrt),
+            ("i686-win7-windows-msvc", WindowsCrt::UcrtRedistributable),
+            ("x86_64-pc-windows-gnu", WindowsCrt::MingwMsvcrt),
+            ("x86_64-win7-windows-gnu", WindowsCrt::MingwMsvcrt),
+            ("aarch64-pc-windows-gnullvm", WindowsCrt::MingwUcrt),
+        ] {
+            let triple = Triple::from_str(triple).unwrap();
+            assert_eq!(triple.windows_crt(), Ok(*crt), "{}", triple);
+        }
+
+        let triple = Triple::from_str("i686-win7-windows-msvc").unwrap();
+        assert_eq!(triple.vendor, Vendor::Win7);
+
+        let triple = Triple::from_str("arm64ec-pc-windows-msvc").unwrap();
+        assert_eq!(
+            triple.architecture,
+            Architecture::Aarch64(Aarch64Architecture::Arm64ec)
+        );
+        assert_eq!(triple.data_model(), Ok(CDataModel::LLP64));
+        assert_eq!(
+            triple.default_calling_convention(),
+            Ok(CallingConvention::WindowsFastcall)
+        );
+
+        assert_eq!(
+            Triple::from_str("x86_64-unknown-linux-gnu")
+                .unwrap()
+                .windows_crt(),
+            Err(())
+        );
+    }
+
+    #[test]
+    fn normalize_apple_os() {
+        for (darwin, normalized) in &[
+            ("x86_64-apple-darwin", "x86_64-apple-macosx"),
+            ("x86_64-apple-darwin19", "x86_64-apple-macosx10.15.0"),
+            ("x86_64h-apple-darwin8", "x86_64h-apple-macosx10.4.0"),
+            ("aarch64-apple-darwin23", "aarch64-apple-macosx14.0.0"),
+            ("aarch64-apple-darwin25", "aarch64-apple-macosx26.0.0"),
+            ("armv7s-apple-darwin16", "armv7s-apple-ios10.0.0"),
+            ("armv7k-apple-darwin22", "armv7k-apple-watchos9.0.0"),
+            ("arm64_32-apple-darwin22", "arm64_32-apple-watchos9.0.0"),
+            ("arm64e-apple-darwin24", "arm64e-apple-macosx15.0.0"),
+            (
+                "aarch64-apple-darwin23-macabi",
+                "aarch64-apple-ios17.0.0-macabi",
+            ),
+            ("x86_64-apple-ios13.1", "x86_64-apple-ios13.1.0"),
+        ] {
+            let triple = Triple::from_str(darwin).unwrap();
+            assert_eq!(
+                triple.normalize_apple_os().map(|t| t.to_string()),
+                Ok(normalized.to_string()),
+                "{}",
+                darwin
+            );
+        }
+
+        for triple in &[
+            "x86_64-unknown-linux-gnu",
+            "aarch64-apple-darwin-sim",
+            "riscv64-apple-darwin",
+            "x86_64-apple-darwin3",
+        ] {
+            let triple = Triple::from_str(triple).unwrap();
+            assert_eq!(triple.normalize_apple_os(), Err(()), "{}", triple);
+        }
+    }
+
+    #[test]
+    fn apple_platforms() {
+        for (triple, platform, sdk) in &[
+            ("x86_64-apple-darwin", ApplePlatform::MacOS, "macosx"),
+            ("aarch64-apple-macosx14.0", ApplePlatform::MacOS, "macosx"),
+            ("aarch64-apple-ios", ApplePlatform::Ios, "iphoneos"),
+            (
+                "x86_64-apple-ios",
+                ApplePlatform::IosSimulator,
+                "iphonesimulator",
+            ),
+            (
+                "aarch64-apple-ios-sim",
+                ApplePlatform::IosSimulator,
+                "iphonesimulator",
+            ),
+            (
+                "aarch64-apple-ios-macabi",
+                ApplePlatform::MacCatalyst,
+                "macosx",
+            ),
+            ("aarch64-apple-tvos", ApplePlatform::TvOS, "appletvos"),
+            (
+                "aarch64-apple-tvos-sim",
+                ApplePlatform::TvOSSimulator,
+                "appletvsimulator",
+            ),
+            ("armv7k-apple-watchos", ApplePlatform::WatchOS, "watchos"),
+            (
+                "x86_64-apple-watchos-sim",
+                ApplePlatform::WatchOSSimulator,
+                "watchsimulator",
+            ),
+            ("aarch64-apple-visionos", ApplePlatform::VisionOS, "xros"),
+            (
+                "aarch64-apple-xros-sim",
+                ApplePlatform::VisionOSSimulator,
+                "xrsimulator",
+            ),
+            (
+                "x86_64-apple-driverkit",
+                ApplePlatform::DriverKit,
+                "driverkit",
+            ),
+        ] {
+            let triple = Triple::from_str(triple).unwrap();
+            assert_eq!(triple.apple_platform(), Ok(*platform), "{}", triple);
+            assert_eq!(triple.apple_sdk_name(), Ok(*sdk), "{}", triple);
+            assert_eq!(ApplePlatform::from_number(platform.number()), Ok(*platform));
+        }
+
+        assert_eq!(ApplePlatform::MacOS.number(), 1);
+        assert_eq!(ApplePlatform::MacCatalyst.number(), 6);
+        assert_eq!(ApplePlatform::IosSimulator.number(), 7);
+        assert_eq!(ApplePlatform::from_number(0), Err(()));
+        assert_eq!(ApplePlatform::BridgeOS.sdk_name(), Err(()));
+
+        let triple = Triple::from_str("arm64_32-apple-watchos").unwrap();
+        assert_eq!(triple.apple_platform(), Ok(ApplePlatform::WatchOS));
+        assert_eq!(triple.pointer_width(), Ok(PointerWidth::U32));
+        assert_eq!(triple.data_model(), Ok(CDataModel::ILP32));
+
+        let triple = Triple::from_str("x86_64-unknown-linux-gnu").unwrap();
+        assert_eq!(triple.apple_platform(), Err(()));
+    }
+
+    #[test]
+    fn apple_simulators() {
+        for (triple, simulator, catalyst) in &[
+            ("aarch64-apple-ios-sim", true, false),
+            ("aarch64-apple-ios-simulator", true, false),
+            ("x86_64-apple-ios", true, false),
+            ("x86_64-apple-tvos13.0-simulator", true, false),
+            ("aarch64-apple-darwin-sim", true, false),
+            ("aarch64-apple-ios", false, false),
+            ("x86_64-apple-darwin", false, false),
+            ("aarch64-apple-ios-macabi", false, true),
+            ("x86_64-apple-ios13.1-macabi", false, true),
+            ("x86_64-unknown-linux-gnu", false, false),
+        ] {
+            let triple = Triple::from_str(triple).unwrap();
+            assert_eq!(triple.is_simulator(), *simulator, "{}", triple);
+            assert_eq!(triple.is_mac_catalyst(), *catalyst, "{}", triple);
+        }
+
+        // The Swift spelling is accepted, but `sim` is the canonical one.
+        let triple = Triple::from_str("arm64-apple-ios17.0-simulator").unwrap();
+        assert_eq!(triple.environment, Environment::Sim);
+        assert_eq!(triple.to_string(), "aarch64-apple-ios17.0.0-sim");
+
+        let triple = Triple::from_str("x86_64-apple-ios13.1-macabi").unwrap();
+        assert_eq!(
+            triple.operating_system,
+            OperatingSystem::IOS(Some(DeploymentTarget::new(13, 1, 0)))
+        );
+
+        for (triple, environment) in &[
+            ("aarch64-apple-macosx-sim", "sim"),
+            ("aarch64-apple-driverkit-simulator", "simulator"),
+            ("x86_64-unknown-linux-sim", "sim"),
+            ("aarch64-apple-tvos-macabi", "macabi"),
+            ("aarch64-apple-macosx-macabi", "macabi"),
+        ] {
+            assert_eq!(
+                Triple::from_str(triple),
+                Err(ParseError::UnrecognizedEnvironment(environment.to_string())),
+                "{}",
+                triple
+            );
+        }
+    }
+
+    #[test]
+    fn libc_version_environments() {
+        for triple in &[
+            "x86_64-unknown-linux-gnu.2.17",
+            "aarch64-unknown-linux-musl.1.2.3",
+            "i686-unknown-hurd-gnu.2.38",
+        ] {
+            assert_eq!(Triple::from_str(triple).unwrap().to_string(), *triple);
+        }
+
+        for (triple, environment) in &[
+            ("x86_64-pc-windows-gnu.2.17", "gnu.2.17"),
+            ("x86_64-unknown-freebsd-gnu.2.17", "gnu.2.17"),
+            ("x86_64-unknown-none-musl.1.2", "musl.1.2"),
+        ] {
+            assert_eq!(
+                Triple::from_str(triple),
+                Err(ParseError::UnrecognizedEnvironment(environment.to_string())),
+                "{}",
+                triple
+            );
+        }
+
+        // Without a version, the environment is allowed anywhere, as before.
+        assert!(Triple::from_str("x86_64-pc-windows-gnu").is_ok());
+    }
+
+    #[test]
+    fn deployment_targets() {
+        let version = |major, minor| DeploymentTarget {
+            major,
+            minor,
+            patch: 0,
+        };
+
+        for (triple, minimum, default) in &[
+            ("x86_64-apple-darwin", version(10, 12), version(10, 12)),
+            ("i686-apple-darwin", version(10, 12), version(10, 12)),
+            ("aarch64-apple-darwin", version(11, 0), version(11, 0)),
+            ("aarch64-apple-ios", version(10, 0), version(10, 0)),
+            ("armv7s-apple-ios", version(10, 0), version(10, 0)),
+            ("aarch64-apple-ios-sim", version(14, 0), version(14, 0)),
+            ("x86_64-apple-ios", version(10, 0), version(10, 0)),
+            ("x86_64-apple-ios-macabi", version(13, 1), version(13, 1)),
+            ("aarch64-apple-ios-macabi", version(14, 0), version(14, 0)),
+            ("aarch64-apple-tvos", version(10, 0), version(10, 0)),
+            ("x86_64-apple-tvos", version(10, 0), version(10, 0)),
+            ("aarch64-apple-tvos-sim", version(14, 0), version(14, 0)),
+            ("armv7k-apple-watchos", version(5, 0), version(5, 0)),
+            ("x86_64-apple-watchos-sim", version(5, 0), version(5, 0)),
+            ("aarch64-apple-watchos-sim", version(7, 0), version(7, 0)),
+            ("aarch64-apple-visionos", version(1, 0), version(1, 0)),
+            ("arm64e-apple-darwin", version(11, 0), version(11, 0)),
+            ("arm64e-apple-ios", version(14, 0), version(14, 0)),
+            ("arm64_32-apple-watchos", version(5, 0), version(5, 0)),
+        ] {
+            let triple = Triple::from_str(triple).unwrap();
+            assert_eq!(
+                triple.minimum_deployment_target(),
+                Ok(*minimum),
+                "{}",
+                triple
+            );
+            assert_eq!(
+                triple.default_deployment_target(),
+                Ok(*default),
+                "{}",
+                triple
+            );
+        }
+
+        let linux = Triple::from_str("x86_64-unknown-linux-gnu").unwrap();
+        assert_eq!(linux.minimum_deployment_target(), Err(()));
+        assert_eq!(linux.default_deployment_target(), Err(()));
+        assert_eq!(linux.deployment_target_env_var(), Err(()));
+
+        let mac = Triple::from_str("x86_64-apple-darwin").unwrap();
+        assert_eq!(
+            mac.deployment_target_env_var(),
+            Ok("MACOSX_DEPLOYMENT_TARGET")
+        );
+        let env = |name: &str| {
+            if name == "MACOSX_DEPLOYMENT_TARGET" {
+                Some("10.15")
+            } else {
+                None
+            }
+        };
+        assert_eq!(mac.resolve_deployment_target(env), Ok(version(10, 15)));
+        assert_eq!(
+            mac.resolve_deployment_target(|_| None::<&str>),
+            Ok(version(10, 12))
+        );
+        assert_eq!(
+            mac.resolve_deployment_target(|_| Some("")),
+            Ok(version(10, 12))
+        );
+        assert_eq!(mac.resolve_deployment_target(|_| Some("ten")), Err(()));
+
+        // Versions below the minimum are raised to it.
+        let arm_mac = Triple::from_str("aarch64-apple-darwin").unwrap();
+        assert_eq!(arm_mac.resolve_deployment_target(env), Ok(version(11, 0)));
+        assert_eq!(
+            mac.resolve_deployment_target(|_| Some("10.7")),
+            Ok(version(10, 12))
+        );
+        let ios = Triple::from_str("aarch64-apple-ios").unwrap();
+        assert_eq!(
+            ios.resolve_deployment_target(|_| Some("9.3")),
+            Ok(version(10, 0))
+        );
+
+        // The version in the triple wins over the environment.
+        let triple = Triple::from_str("aarch64-apple-macosx13.0").unwrap();
+        assert_eq!(triple.resolve_deployment_target(env), Ok(version(13, 0)));
+
+        let catalyst = Triple::from_str("aarch64-apple-ios-macabi").unwrap();
+        assert_eq!(
+            catalyst.deployment_target_env_var(),
+            Ok("IPHONEOS_DEPLOYMENT_TARGET")
+        );
+        assert_eq!(
+            catalyst.resolve_deployment_target(|_| Some("15.2".to_string())),
+            Ok(version(15, 2))
+        );
+    }
+
     #[test]
     fn p32_abi() {
         // Test that special 32-bit pointer ABIs on 64-bit architectures are
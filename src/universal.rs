@@ -0,0 +1,184 @@
+// This file defines the `UniversalTarget` type, which groups the triples that
+// are combined into one Apple universal binary.
+
+use crate::targets::{Aarch64Architecture, Architecture, ArmArchitecture, X86_32Architecture};
+use crate::triple::Triple;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::mem;
+
+/// A set of Apple triples that are built into one universal ("fat") binary,
+/// such as `x86_64-apple-macosx` together with `arm64-apple-macosx`.
+///
+/// The triples all target the same Apple OS, and each one provides a
+/// different architecture slice. The `x86_64h` slice, for Haswell and later
+/// Intel processors, is distinct from the plain `x86_64` one, so both may be
+/// present.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct UniversalTarget {
+    triples: Vec<Triple>,
+}
+
+impl UniversalTarget {
+    /// Group the given triples into a universal target.
+    ///
+    /// Returns `Err(())` if there are no triples, if any of them isn't for an
+    /// Apple OS with a known architecture name, if they don't all target the
+    /// same OS and environment, or if two of them would produce the same
+    /// architecture slice.
+    pub fn new<I: IntoIterator<Item = Triple>>(triples: I) -> Result<Self, ()> {
+        let triples: Vec<Triple> = triples.into_iter().collect();
+        let first = triples.first().ok_or(())?.normalize_apple_os()?;
+
+        let mut names = Vec::with_capacity(triples.len());
+        for triple in &triples {
+            let normalized = triple.normalize_apple_os()?;
+
+            // Deployment targets commonly differ between the slices, since
+            // newer architectures require newer OS releases, so only compare
+            // which OS it is.
+            if mem::discriminant(&normalized.operating_system)
+                != mem::discriminant(&first.operating_system)
+                || normalized.is_simulator() != first.is_simulator()
+                || normalized.is_mac_catalyst() != first.is_mac_catalyst()
+            {
+                return Err(());
+            }
+
+            let name = apple_arch_name(triple.architecture)?;
+            if names.contains(&name) {
+                return Err(());
+            }
+            names.push(name);
+        }
+
+        Ok(Self { triples })
+    }
+
+    /// Return the triples in this universal target, in the order they were
+    /// given.
+    pub fn triples(&self) -> &[Triple] {
+        &self.triples
+    }
+
+    /// Return Apple's names for the architectures in this universal target,
+    /// such as `arm64` and `x86_64h`.
+    pub fn arch_names(&self) -> Vec<&'static str> {
+        self.triples
+            .iter()
+            .map(|triple| apple_arch_name(triple.architecture).unwrap())
+            .collect()
+    }
+
+    /// Return the architectures as a space-separated list, in the format
+    /// printed by `lipo -archs` and accepted by `lipo -verify_arch`.
+    pub fn lipo_archs(&self) -> String {
+        self.arch_names().join(" ")
+    }
+
+    /// Return an `ARCHS=...` build setting that makes `xcodebuild` build all
+    /// the architectures. This is usually passed along with
+    /// `ONLY_ACTIVE_ARCH=NO`.
+    pub fn xcodebuild_archs(&self) -> String {
+        let mut setting = String::from("ARCHS=");
+        setting.push_str(&self.lipo_archs());
+        setting
+    }
+}
+
+/// Return the name that Apple's tools use for an architecture, or `Err(())`
+/// if it isn't one that Apple platforms use.
+fn apple_arch_name(architecture: Architecture) -> Result<&'static str, ()> {
+    Ok(match architecture {
+        Architecture::Aarch64(Aarch64Architecture::Aarch64) => "arm64",
+        Architecture::Aarch64(Aarch64Architecture::Arm64e) => "arm64e",
+        Architecture::Aarch64(Aarch64Architecture::Arm64_32) => "arm64_32",
+        Architecture::Arm(ArmArchitecture::Armv6) => "armv6",
+        Architecture::Arm(ArmArchitecture::Armv7) => "armv7",
+        Architecture::Arm(ArmArchitecture::Armv7k) => "armv7k",
+        Architecture::Arm(ArmArchitecture::Armv7s) => "armv7s",
+        Architecture::Powerpc => "ppc",
+        Architecture::Powerpc64 => "ppc64",
+        Architecture::X86_32(X86_32Architecture::I386)
+        | Architecture::X86_32(X86_32Architecture::I686) => "i386",
+        Architecture::X86_64 => "x86_64",
+        Architecture::X86_64h => "x86_64h",
+        _ => return Err(()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::str::FromStr;
+
+    fn universal(triples: &[&str]) -> Result<UniversalTarget, ()> {
+        UniversalTarget::new(triples.iter().map(|s| Triple::from_str(s).unwrap()))
+    }
+
+    #[test]
+    fn macos_universal() {
+        let target = universal(&["x86_64-apple-macosx10.12", "arm64-apple-macosx11.0"]).unwrap();
+        assert_eq!(target.triples().len(), 2);
+        assert_eq!(target.arch_names(), ["x86_64", "arm64"]);
+        assert_eq!(target.lipo_archs(), "x86_64 arm64");
+        assert_eq!(target.xcodebuild_archs(), "ARCHS=x86_64 arm64");
+
+        // `darwin` triples are the same OS as `macosx` ones.
+        let target = universal(&[
+            "x86_64-apple-darwin",
+            "x86_64h-apple-darwin",
+            "aarch64-apple-macosx",
+        ])
+        .unwrap();
+        assert_eq!(target.lipo_archs(), "x86_64 x86_64h arm64");
+
+        let target = universal(&["arm64-apple-macosx", "arm64e-apple-macosx"]).unwrap();
+        assert_eq!(target.lipo_archs(), "arm64 arm64e");
+
+        let target = universal(&["i686-apple-darwin", "powerpc-apple-darwin"]).unwrap();
+        assert_eq!(target.lipo_archs(), "i386 ppc");
+    }
+
+    #[test]
+    fn other_universal() {
+        let target =
+            universal(&["armv7-apple-ios", "armv7s-apple-ios", "aarch64-apple-ios"]).unwrap();
+        assert_eq!(target.lipo_archs(), "armv7 armv7s arm64");
+
+        let target = universal(&["armv7k-apple-watchos", "arm64_32-apple-watchos"]).unwrap();
+        assert_eq!(target.lipo_archs(), "armv7k arm64_32");
+
+        let target = universal(&["x86_64-apple-ios", "aarch64-apple-ios-sim"]).unwrap();
+        assert_eq!(target.lipo_archs(), "x86_64 arm64");
+
+        let target = universal(&["x86_64-apple-ios-macabi", "aarch64-apple-ios-macabi"]).unwrap();
+        assert_eq!(target.lipo_archs(), "x86_64 arm64");
+    }
+
+    #[test]
+    fn invalid_universal() {
+        assert_eq!(UniversalTarget::new(Vec::new()), Err(()));
+        assert_eq!(universal(&["x86_64-unknown-linux-gnu"]), Err(()));
+        assert_eq!(
+            universal(&["x86_64-apple-darwin", "aarch64-apple-ios"]),
+            Err(())
+        );
+        assert_eq!(
+            universal(&["aarch64-apple-ios", "aarch64-apple-ios-sim"]),
+            Err(())
+        );
+        assert_eq!(
+            universal(&["aarch64-apple-ios", "x86_64-apple-ios-macabi"]),
+            Err(())
+        );
+        assert_eq!(
+            universal(&["x86_64-apple-macosx10.12", "x86_64-apple-macosx11.0"]),
+            Err(())
+        );
+        assert_eq!(
+            universal(&["i386-apple-darwin", "i686-apple-darwin"]),
+            Err(())
+        );
+    }
+}